@@ -1,8 +1,16 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
+use std::io;
 
-use iced::widget::{checkbox, column, container, horizontal_space, responsive, scrollable, text};
-use iced::{Application, Command, Element, Length, Renderer, Theme};
-use iced_table::table::{self, Width};
+use iced::widget::{
+    button, checkbox, column, container, horizontal_space, responsive, text, text_input, Row,
+};
+use iced::{Application, Color, Command, Element, Length, Renderer, Theme};
+use iced_table::table::{
+    self, export, ColumnGroup, PasteError, PastedCell, Pin, RangeSelection, SelectionMode, Sort,
+    SortDirection, Span, Table, TreeRow, Width,
+};
 
 fn main() {
     App::run(Default::default()).unwrap()
@@ -10,23 +18,62 @@ fn main() {
 
 #[derive(Debug, Clone)]
 pub enum Message {
-    SyncHeader(scrollable::AbsoluteOffset),
     Resizing(usize, f32),
     Resized,
+    RowResizing(Vec<usize>, f32),
+    RowResized,
+    Autofit(usize, f32),
+    Reordered(usize, usize),
+    ColumnVisibility(usize, bool),
+    Filter(usize, String),
+    Search(String),
+    Sort(usize, SortDirection),
+    MultiSort(usize, SortDirection),
+    SelectionChanged(Vec<u64>),
+    RangeSelected(RangeSelection),
+    Export,
+    CellEdited(Vec<usize>, usize, String),
+    Pasted(Vec<usize>, usize, Vec<Vec<PastedCell>>),
+    PasteFailed(PasteError),
+    GroupToggled(String),
+    ExpandToggled(u64, bool),
     ResizeColumnsEnabled(bool),
+    ResizeRowsEnabled(bool),
+    ManagedWidthsEnabled(bool),
     FooterEnabled(bool),
+    ColumnGroupsEnabled(bool),
+    FilterRowEnabled(bool),
+    GroupRowsEnabled(bool),
+    TreeRowsEnabled(bool),
+    PinnedRowEnabled(bool),
     MinWidthEnabled(bool),
     DarkThemeEnabled(bool),
 }
 
 pub struct App {
     columns: Vec<Column>,
-    rows: Vec<usize>,
-    header: scrollable::Id,
-    body: scrollable::Id,
-    footer: scrollable::Id,
+    column_groups: Vec<Vec<ColumnGroup>>,
+    rows: Vec<Item>,
+    row_heights: HashMap<Vec<usize>, f32>,
+    row_offset: Option<(Vec<usize>, f32)>,
+    sorting: Vec<Sort>,
+    selected: Vec<u64>,
+    range: Option<RangeSelection>,
+    hidden: Vec<u64>,
+    collapsed: Vec<String>,
+    expanded: Vec<u64>,
+    search: String,
+    // Changed along with the content of the cells, for auto columns to fit it
+    revision: u64,
     resize_columns_enabled: bool,
+    resize_rows_enabled: bool,
+    managed_widths_enabled: bool,
     footer_enabled: bool,
+    column_groups_enabled: bool,
+    filter_row_enabled: bool,
+    group_rows_enabled: bool,
+    tree_rows_enabled: bool,
+    pinned_row_enabled: bool,
     min_width_enabled: bool,
     theme: Theme,
 }
@@ -35,12 +82,20 @@ impl Default for App {
     fn default() -> Self {
         Self {
             columns: vec![
-                Column::new(Letter::A, Width::Fixed(100.0)),
+                Column::new(
+                    Letter::A,
+                    Width::Auto {
+                        min: 60.0,
+                        max: 200.0,
+                    },
+                ),
                 Column::new(
                     Letter::B,
                     Width::Resizable {
                         initial: 100.0,
                         offset: 0.0,
+                        min: Some(50.0),
+                        max: Some(250.0),
                     },
                 ),
                 Column::new(
@@ -62,15 +117,47 @@ impl Default for App {
                     Width::Resizable {
                         initial: 100.0,
                         offset: 0.0,
+                        min: Some(50.0),
+                        max: Some(250.0),
                     },
                 ),
             ],
-            rows: (1..=50).collect(),
-            header: scrollable::Id::unique(),
-            body: scrollable::Id::unique(),
-            footer: scrollable::Id::unique(),
+            column_groups: vec![
+                vec![
+                    ColumnGroup {
+                        label: "A to C".to_string(),
+                        columns: vec![0, 1, 2],
+                    },
+                    ColumnGroup {
+                        label: "D & E".to_string(),
+                        columns: vec![3, 4],
+                    },
+                ],
+                vec![ColumnGroup {
+                    label: "B & C".to_string(),
+                    columns: vec![1, 2],
+                }],
+            ],
+            rows: (1..=50).map(|number| Item::new(number, 2)).collect(),
+            row_heights: HashMap::new(),
+            row_offset: None,
+            sorting: vec![],
+            selected: vec![],
+            range: None,
+            hidden: vec![],
+            collapsed: vec![],
+            expanded: vec![],
+            search: String::new(),
+            revision: 0,
             resize_columns_enabled: true,
+            resize_rows_enabled: false,
+            managed_widths_enabled: false,
             footer_enabled: true,
+            column_groups_enabled: false,
+            filter_row_enabled: true,
+            group_rows_enabled: false,
+            tree_rows_enabled: false,
+            pinned_row_enabled: false,
             min_width_enabled: true,
             theme: Theme::Light,
         }
@@ -97,33 +184,148 @@ impl Application for App {
 
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
         match message {
-            Message::SyncHeader(offset) => {
-                return Command::batch(vec![
-                    scrollable::scroll_to(self.header.clone(), offset),
-                    scrollable::scroll_to(self.footer.clone(), offset),
-                ])
-            }
             Message::Resizing(index, offset) => {
                 if let Some(column) = self.columns.get_mut(index) {
                     if let Width::Resizable {
-                        initial, offset: old_offset
+                        offset: old_offset, ..
                     } = &mut column.width
                     {
-                        *old_offset = (*initial + offset).clamp(50.0, 250.0) - *initial;
+                        *old_offset = offset;
                     }
                 }
             }
             Message::Resized => self.columns.iter_mut().for_each(|column| {
                 if let Width::Resizable {
-                    initial, offset
+                    initial, offset, ..
                 } = &mut column.width
                 {
-                    *initial = (*initial + *offset).clamp(50.0, 250.0);
+                    *initial += *offset;
                     *offset = 0.0;
                 }
             }),
+            Message::Export => {
+                if let Err(error) = self.table().export(io::stdout(), export::Format::Csv) {
+                    eprintln!("Export failed: {error}");
+                }
+            }
+            Message::RowResizing(path, offset) => self.row_offset = Some((path, offset)),
+            Message::RowResized => {
+                if let Some((path, offset)) = self.row_offset.take() {
+                    *self.row_heights.entry(path).or_insert(ROW_HEIGHT) += offset;
+                }
+            }
+            Message::Autofit(index, width) => {
+                if let Some(column) = self.columns.get_mut(index) {
+                    if let Width::Resizable {
+                        initial, offset, ..
+                    } = &mut column.width
+                    {
+                        *initial = width;
+                        *offset = 0.0;
+                    }
+                }
+            }
+            Message::Reordered(from, to) => {
+                let column = self.columns.remove(from);
+                self.columns.insert(to, column);
+
+                // Sorted columns are referenced by index
+                for sort in &mut self.sorting {
+                    sort.column = if sort.column == from {
+                        to
+                    } else if from < sort.column && sort.column <= to {
+                        sort.column - 1
+                    } else if to <= sort.column && sort.column < from {
+                        sort.column + 1
+                    } else {
+                        sort.column
+                    };
+                }
+            }
+            Message::ColumnVisibility(index, is_visible) => {
+                if let Some(column) = self.columns.get(index) {
+                    let key = column.letter as u64;
+
+                    if is_visible {
+                        self.hidden.retain(|hidden| *hidden != key);
+                    } else if !self.hidden.contains(&key) {
+                        self.hidden.push(key);
+                    }
+                }
+            }
+            Message::Filter(index, filter) => {
+                if let Some(column) = self.columns.get_mut(index) {
+                    column.filter = filter;
+                }
+            }
+            Message::Search(search) => {
+                // Each column highlights the matches in its cells
+                for column in &mut self.columns {
+                    column.search = search.clone();
+                }
+
+                self.search = search;
+            }
+            Message::Sort(column, direction) => {
+                self.sorting = vec![Sort { column, direction }];
+                self.sorting
+                    .retain(|sort| sort.direction != SortDirection::Unsorted);
+            }
+            Message::MultiSort(column, direction) => {
+                if let Some(sort) = self.sorting.iter_mut().find(|sort| sort.column == column) {
+                    sort.direction = direction;
+                } else {
+                    self.sorting.push(Sort { column, direction });
+                }
+
+                self.sorting
+                    .retain(|sort| sort.direction != SortDirection::Unsorted);
+            }
+            Message::SelectionChanged(selected) => self.selected = selected,
+            Message::RangeSelected(range) => self.range = Some(range),
+            Message::CellEdited(path, column, value) => {
+                let number = self.row(&path).map(|row| row.number);
+
+                if let Some((number, column)) = number.zip(self.columns.get_mut(column)) {
+                    column.edits.insert(number, value);
+                    self.revision += 1;
+                }
+            }
+            Message::Pasted(_, _, block) => {
+                for cell in block.into_iter().flatten() {
+                    let number = self.row(&cell.row).map(|row| row.number);
+
+                    if let Some((number, column)) = number.zip(self.columns.get_mut(cell.column)) {
+                        column.edits.insert(number, cell.value);
+                    }
+                }
+
+                self.revision += 1;
+            }
+            Message::PasteFailed(error) => eprintln!("Paste failed: {error}"),
+            Message::GroupToggled(group) => {
+                if self.collapsed.contains(&group) {
+                    self.collapsed.retain(|collapsed| *collapsed != group);
+                } else {
+                    self.collapsed.push(group);
+                }
+            }
+            Message::ExpandToggled(key, is_expanded) => {
+                if is_expanded {
+                    self.expanded.push(key);
+                } else {
+                    self.expanded.retain(|expanded| *expanded != key);
+                }
+            }
             Message::ResizeColumnsEnabled(enabled) => self.resize_columns_enabled = enabled,
+            Message::ResizeRowsEnabled(enabled) => self.resize_rows_enabled = enabled,
+            Message::ManagedWidthsEnabled(enabled) => self.managed_widths_enabled = enabled,
             Message::FooterEnabled(enabled) => self.footer_enabled = enabled,
+            Message::ColumnGroupsEnabled(enabled) => self.column_groups_enabled = enabled,
+            Message::FilterRowEnabled(enabled) => self.filter_row_enabled = enabled,
+            Message::GroupRowsEnabled(enabled) => self.group_rows_enabled = enabled,
+            Message::TreeRowsEnabled(enabled) => self.tree_rows_enabled = enabled,
+            Message::PinnedRowEnabled(enabled) => self.pinned_row_enabled = enabled,
             Message::MinWidthEnabled(enabled) => self.min_width_enabled = enabled,
             Message::DarkThemeEnabled(enabled) => {
                 if enabled {
@@ -139,19 +341,48 @@ impl Application for App {
 
     fn view(&self) -> Element<Self::Message> {
         let table = responsive(|size| {
-            let mut table = table::table(
-                self.header.clone(),
-                self.body.clone(),
-                &self.columns,
-                &self.rows,
-                Message::SyncHeader,
-            );
+            let mut table = self
+                .table()
+                .on_sort(Message::Sort)
+                .on_multi_sort(Message::MultiSort)
+                .on_column_reorder(Message::Reordered)
+                .on_column_visibility(Message::ColumnVisibility)
+                .selection(SelectionMode::Multi, |row| row.number as u64)
+                .selected(&self.selected)
+                .on_selection_change(Message::SelectionChanged)
+                .on_range_select(Message::RangeSelected)
+                .on_cell_edit(Message::CellEdited)
+                .on_paste(Message::Pasted)
+                .on_paste_error(Message::PasteFailed)
+                .copyable();
 
             if self.resize_columns_enabled {
-                table = table.on_column_resize(Message::Resizing, Message::Resized);
+                table = table
+                    .on_column_resize(Message::Resizing, Message::Resized)
+                    .on_column_autofit(Message::Autofit);
+            }
+            if self.resize_rows_enabled {
+                table = table
+                    .row_heights(move |path, _row| self.row_height(path))
+                    .on_row_resize(Message::RowResizing, Message::RowResized);
+            }
+            if self.managed_widths_enabled {
+                table = table.managed_resizing();
             }
             if self.footer_enabled {
-                table = table.footer(self.footer.clone());
+                table = table.footer();
+            }
+            if self.column_groups_enabled {
+                table = table.column_groups(&self.column_groups);
+            }
+            if self.filter_row_enabled {
+                table = table.filter_row();
+            }
+            if self.group_rows_enabled {
+                table = table.on_group_toggle(Message::GroupToggled);
+            }
+            if self.tree_rows_enabled {
+                table = table.on_toggle_expand(Message::ExpandToggled);
             }
             if self.min_width_enabled {
                 table = table.min_width(size.width);
@@ -161,12 +392,53 @@ impl Application for App {
         });
 
         let content = column![
+            text_input(
+                "Search (F3 / Shift+F3 for the next / previous match)",
+                &self.search
+            )
+            .on_input(Message::Search),
+            button("Export CSV").on_press(Message::Export),
             checkbox(
                 "Resize Columns",
                 self.resize_columns_enabled,
                 Message::ResizeColumnsEnabled
             ),
+            checkbox(
+                "Resize Rows",
+                self.resize_rows_enabled,
+                Message::ResizeRowsEnabled
+            ),
+            checkbox(
+                "Managed Widths",
+                self.managed_widths_enabled,
+                Message::ManagedWidthsEnabled
+            ),
             checkbox("Footer", self.footer_enabled, Message::FooterEnabled),
+            checkbox(
+                "Column Groups",
+                self.column_groups_enabled,
+                Message::ColumnGroupsEnabled
+            ),
+            checkbox(
+                "Filter Row",
+                self.filter_row_enabled,
+                Message::FilterRowEnabled
+            ),
+            checkbox(
+                "Group Rows",
+                self.group_rows_enabled,
+                Message::GroupRowsEnabled
+            ),
+            checkbox(
+                "Tree Rows",
+                self.tree_rows_enabled,
+                Message::TreeRowsEnabled
+            ),
+            checkbox(
+                "Pinned Row",
+                self.pinned_row_enabled,
+                Message::PinnedRowEnabled
+            ),
             checkbox(
                 "Min Width",
                 self.min_width_enabled,
@@ -177,6 +449,14 @@ impl Application for App {
                 matches!(self.theme, Theme::Dark),
                 Message::DarkThemeEnabled
             ),
+            text(match &self.range {
+                Some(range) => format!(
+                    "Range: {} rows × {} columns (drag across cells to select)",
+                    range.rows.len(),
+                    range.columns.len()
+                ),
+                None => "Range: none (drag across cells to select)".to_string(),
+            }),
             table,
         ]
         .spacing(6);
@@ -191,17 +471,81 @@ impl Application for App {
     }
 }
 
+impl App {
+    // The table with the rows as shown, shared by the view & the export
+    fn table(&self) -> Table<'_, Column, Item, Message, Renderer> {
+        let mut table = table::table(&self.columns, &self.rows)
+            .sorting(&self.sorting)
+            .column_key(|column| column.letter as u64)
+            .hidden_columns(&self.hidden)
+            .search(&self.search)
+            .revision(self.revision);
+
+        if self.group_rows_enabled {
+            table = table
+                .group_by(|row| format!("Rows {}0s", row.number / 10))
+                .collapsed_groups(&self.collapsed);
+        }
+        if self.tree_rows_enabled {
+            table = table.tree(&self.expanded, |row| row.number as u64);
+        }
+        if self.pinned_row_enabled {
+            table = table.pinned_rows(1);
+        }
+
+        table
+    }
+
+    // The row at `path`, down through the children of its top-level row
+    fn row(&self, path: &[usize]) -> Option<&Item> {
+        let (&index, path) = path.split_first()?;
+
+        path.iter()
+            .try_fold(self.rows.get(index)?, |row, &index| row.children.get(index))
+    }
+
+    // The stored height of a row, plus the offset it's being resized by
+    fn row_height(&self, path: &[usize]) -> f32 {
+        let height = self.row_heights.get(path).copied().unwrap_or(ROW_HEIGHT);
+
+        match &self.row_offset {
+            Some((resizing, offset)) if resizing == path => height + offset,
+            _ => height,
+        }
+    }
+}
+
+// Height of a cell, along with its padding
+const ROW_HEIGHT: f32 = 32.0;
+
 struct Column {
     letter: Letter,
     width: Width,
+    filter: String,
+    search: String,
+    edits: HashMap<usize, String>,
 }
 
 impl Column {
     fn new(letter: Letter, width: Width) -> Self {
-        Self { letter, width }
+        Self {
+            letter,
+            width,
+            filter: String::new(),
+            search: String::new(),
+            edits: HashMap::new(),
+        }
+    }
+
+    fn label(&self, row: &Item) -> String {
+        self.edits
+            .get(&row.number)
+            .cloned()
+            .unwrap_or_else(|| format!("Cell {}{row}", self.letter))
     }
 }
 
+#[derive(Clone, Copy)]
 enum Letter {
     A,
     B,
@@ -223,8 +567,40 @@ impl fmt::Display for Letter {
     }
 }
 
+struct Item {
+    number: usize,
+    children: Vec<Item>,
+}
+
+impl Item {
+    // Each row has 3 children, down to `depth` levels
+    fn new(number: usize, depth: usize) -> Self {
+        let children = if depth > 0 {
+            (1..=3)
+                .map(|index| Item::new(number * 100 + index, depth - 1))
+                .collect()
+        } else {
+            vec![]
+        };
+
+        Self { number, children }
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.number.fmt(f)
+    }
+}
+
+impl TreeRow for Item {
+    fn children(&self) -> &[Self] {
+        &self.children
+    }
+}
+
 impl<'a, 'b> table::Column<'a, 'b, Message, Renderer> for Column {
-    type Row = usize;
+    type Row = Item;
 
     fn header(&'b self, _col_index: usize) -> Element<'a, Message> {
         container(text(format!("Column {}", self.letter)))
@@ -236,16 +612,49 @@ impl<'a, 'b> table::Column<'a, 'b, Message, Renderer> for Column {
     fn cell(
         &'b self,
         _col_index: usize,
-        row_index: usize,
-        _row: &'b Self::Row,
+        _row_path: &[usize],
+        row: &'b Self::Row,
     ) -> Element<'a, Message> {
-        container(text(format!("Cell {}{row_index}", self.letter)))
+        let label = self.label(row);
+        let mut pieces = vec![];
+        let mut end = 0;
+
+        // Highlight the text matching the search
+        for range in table::match_ranges(&label, &self.search) {
+            pieces.push(text(&label[end..range.start]).into());
+            pieces.push(
+                text(&label[range.clone()])
+                    .style(Color::from_rgb(0.9, 0.5, 0.0))
+                    .into(),
+            );
+            end = range.end;
+        }
+
+        pieces.push(text(&label[end..]).into());
+
+        container(Row::with_children(pieces))
             .height(24)
             .center_y()
             .into()
     }
 
-    fn footer(&'b self, _col_index: usize, rows: &'b [Self::Row]) -> Option<Element<'a, Message>> {
+    fn span(&self, row: &Self::Row) -> Span {
+        match self.letter {
+            // Across columns C & D on every tenth row
+            Letter::C if row.number % 10 == 0 => Span {
+                columns: 2,
+                ..Span::default()
+            },
+            // Down to the next row, unless virtualized
+            Letter::B if row.number % 10 == 5 => Span {
+                rows: 2,
+                ..Span::default()
+            },
+            _ => Span::default(),
+        }
+    }
+
+    fn footer(&'b self, _col_index: usize, rows: &[&'b Self::Row]) -> Option<Element<'a, Message>> {
         let content = if matches!(self.letter, Letter::C) {
             Element::from(text(format!("Count: {}", rows.len())))
         } else {
@@ -255,7 +664,79 @@ impl<'a, 'b> table::Column<'a, 'b, Message, Renderer> for Column {
         Some(container(content).height(24).center_y().into())
     }
 
+    fn group_footer(
+        &'b self,
+        _col_index: usize,
+        _group: &str,
+        rows: &[&'b Self::Row],
+    ) -> Option<Element<'a, Message>> {
+        matches!(self.letter, Letter::C).then(|| {
+            let subtotal: usize = rows.iter().map(|row| row.number).sum();
+
+            container(text(format!("Subtotal: {subtotal}")))
+                .height(24)
+                .center_y()
+                .into()
+        })
+    }
+
+    fn has_group_footer(&self) -> bool {
+        matches!(self.letter, Letter::C)
+    }
+
+    fn filter(&'b self, col_index: usize) -> Option<Element<'a, Message>> {
+        matches!(self.letter, Letter::B).then(|| {
+            text_input("Filter", &self.filter)
+                .on_input(move |filter| Message::Filter(col_index, filter))
+                .size(14)
+                .into()
+        })
+    }
+
+    fn matches_filter(&self, row: &Self::Row) -> bool {
+        row.to_string().contains(&self.filter)
+    }
+
+    fn text(&self, row: &Self::Row) -> Option<String> {
+        Some(self.label(row))
+    }
+
+    fn export_header(&self) -> Option<String> {
+        Some(format!("Column {}", self.letter))
+    }
+
+    fn is_editable(&self) -> bool {
+        matches!(self.letter, Letter::E)
+    }
+
+    fn editor(
+        &'b self,
+        _col_index: usize,
+        _row_path: &[usize],
+        _row: &'b Self::Row,
+        value: &str,
+    ) -> Option<Element<'a, String>> {
+        Some(
+            text_input("", value)
+                .on_input(|value| value)
+                .size(14)
+                .into(),
+        )
+    }
+
     fn width(&self) -> Width {
         self.width
     }
+
+    fn is_sortable(&self) -> bool {
+        true
+    }
+
+    fn compare(&self, a: &Self::Row, b: &Self::Row) -> Ordering {
+        a.number.cmp(&b.number)
+    }
+
+    fn pin(&self) -> Option<Pin> {
+        matches!(self.letter, Letter::A).then_some(Pin::Left)
+    }
 }
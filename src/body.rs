@@ -0,0 +1,1550 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashSet;
+use std::ops::Range;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, keyboard, mouse, overlay, Color, Element, Length, Point, Rectangle, Size};
+use iced_core::{renderer, window, Clipboard, Shell, Vector};
+use iced_widget::{container, Column, Space};
+
+use crate::editor::Draft;
+use crate::merge::Heights;
+use crate::sticky::{self, Sticky};
+use crate::style::{self, wrapper::Target, StyleSheet};
+use crate::table::SelectionMode;
+
+// Distance from the edge of the viewport at which dragging across cells scrolls the body
+const AUTO_SCROLL_EDGE: f32 = 16.0;
+
+struct State {
+    tree: RefCell<Tree>,
+    range: Cell<(usize, usize)>,
+    viewport: Cell<Option<Rectangle>>,
+    modifiers: keyboard::Modifiers,
+    // Key of the row a shift-click range starts from
+    anchor: Option<u64>,
+    focus: Option<CellRef>,
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+    // Cell being edited, along with its value
+    editing: Option<(CellRef, Draft)>,
+    // Bounds, relative to the body, the frame should scroll into view
+    scroll_to: Option<Rectangle>,
+    // Cell a range is dragged from, and the cell it's dragged to
+    cells: Option<(CellRef, CellRef)>,
+    is_dragging: bool,
+    // Last position of the cursor while dragging, in window coordinates
+    drag_cursor: Point,
+}
+
+// A cell of the body, by the position of its row along with the key of that row, if any, so
+// the row can be found again once the rows are sorted, filtered or grouped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CellRef {
+    position: usize,
+    key: Option<u64>,
+    column: usize,
+}
+
+impl CellRef {
+    fn cell(self) -> (usize, usize) {
+        (self.position, self.column)
+    }
+}
+
+/// Takes the bounds, relative to the [`Body`] owning `tree`, it requested to be scrolled into view.
+pub(crate) fn take_scroll_request(tree: &mut Tree) -> Option<Rectangle> {
+    tree.state.downcast_mut::<State>().scroll_to.take()
+}
+
+/// The rows selected in a [`Body`], by their stable [key](Body::row_key).
+///
+/// Positions without a key, such as group headers, can't be selected.
+pub(crate) struct Selection<'a, Message> {
+    pub mode: SelectionMode,
+    pub selected: &'a [u64],
+    pub on_change: fn(Vec<u64>) -> Message,
+}
+
+/// The editable cells of a [`Body`].
+pub(crate) struct Edit<'a, Message> {
+    /// The value a cell starts with when edited, or `None` if it can't be edited.
+    pub value: Box<dyn Fn(usize, usize) -> Option<String> + 'a>,
+    pub on_commit: Box<dyn Fn(usize, usize, String) -> Option<Message> + 'a>,
+}
+
+/// The height of each row of a virtualized [`Body`], by position, known before it's built.
+#[derive(Debug, Clone)]
+pub(crate) struct RowHeights {
+    // Top of each row, followed by the bottom of the last
+    offsets: Vec<f32>,
+}
+
+impl RowHeights {
+    pub fn new(heights: impl IntoIterator<Item = f32>) -> Self {
+        let mut offsets = vec![0.0];
+
+        for height in heights {
+            offsets.push(offsets[offsets.len() - 1] + height.max(0.0));
+        }
+
+        Self { offsets }
+    }
+
+    fn top(&self, position: usize) -> f32 {
+        self.offsets[position.min(self.offsets.len() - 1)]
+    }
+
+    fn height(&self, position: usize) -> f32 {
+        self.top(position + 1) - self.top(position)
+    }
+
+    // Total height of the rows in `range`
+    fn sum(&self, range: Range<usize>) -> f32 {
+        self.top(range.end) - self.top(range.start)
+    }
+
+    // Position of the row at `y`, or the number of rows past the last one
+    fn position(&self, y: f32) -> usize {
+        self.offsets
+            .partition_point(|&top| top <= y)
+            .saturating_sub(1)
+    }
+}
+
+/// The body of a [`Table`](crate::Table).
+///
+/// Rows are built lazily through `row`. When `row_heights` are provided, only the rows
+/// intersecting the viewport (plus `overscan` rows either side) are built, with spacers
+/// standing in for the rest.
+///
+/// Rows are addressed by their position in the body, which differs from their index in the
+/// table's rows once sorted. Given a `row_key`, the focused cell, the cell being edited and the
+/// range of cells dragged across are kept on their rows as the rows change, and cleared once a
+/// row is left out.
+///
+/// The first `pinned` rows are always built, and stick to the top of the viewport above
+/// the other rows, separated by a line of `divider_width`.
+///
+/// Pressing Left / Right on a focused row collapses / expands it through `on_expand`, if
+/// it returns a message, rather than moving the focus.
+///
+/// An editable cell is edited by double-clicking it or pressing Enter, which builds the row
+/// with the [`Draft`] of that cell. Enter, or clicking elsewhere, commits the edit while Escape
+/// cancels it, and Tab / Shift+Tab commits it to edit the next / previous editable cell.
+///
+/// Pressing F3 / Shift+F3 focuses the next / previous of the `matches` of a search, whether
+/// or not the body has focus.
+///
+/// Pressing Ctrl+C writes the selected rows, or the focused cell when none are, to the
+/// clipboard through `copy`, given their positions and the range of columns copied. Pressing
+/// Ctrl+V passes the text of the clipboard to `paste`, along with the focused cell.
+///
+/// When `on_range_select` is set, dragging from a cell selects the range of cells up to the
+/// one under the cursor, which is then copied by Ctrl+C instead. The body is scrolled while
+/// the cursor nears the edge of the viewport. Moving the focus, or pressing on another cell,
+/// clears the range.
+///
+/// Unless virtualized, the height of each row is recorded in `heights` as it's laid out, for
+/// the cells merged with the rows beneath them.
+pub(crate) struct Body<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    len: usize,
+    columns: usize,
+    row_heights: Option<RowHeights>,
+    overscan: usize,
+    pinned: usize,
+    divider_width: f32,
+    row: Box<dyn Fn(usize, Option<(usize, Draft)>) -> Element<'a, Message, Renderer> + 'a>,
+    row_key: Option<Box<dyn Fn(usize) -> Option<u64> + 'a>>,
+    selection: Option<Selection<'a, Message>>,
+    on_activate: Option<Box<dyn Fn(usize, usize) -> Option<Message> + 'a>>,
+    on_expand: Option<Box<dyn Fn(usize, bool) -> Option<Message> + 'a>>,
+    edit: Option<Edit<'a, Message>>,
+    // Position & column of each cell matching the search, in order
+    matches: Vec<(usize, usize)>,
+    copy: Option<Box<dyn Fn(&[usize], Range<usize>) -> String + 'a>>,
+    paste: Option<Box<dyn Fn(usize, usize, String) -> Option<Message> + 'a>>,
+    on_range_select: Option<Box<dyn Fn(Range<usize>, Range<usize>) -> Option<Message> + 'a>>,
+    heights: Option<Heights>,
+    style: <Renderer::Theme as style::StyleSheet>::Style,
+    content: RefCell<Content<'a, Message, Renderer>>,
+}
+
+struct Content<'a, Message, Renderer> {
+    range: Range<usize>,
+    element: Option<Element<'a, Message, Renderer>>,
+    limits: layout::Limits,
+    layout: layout::Node,
+}
+
+impl<'a, Message, Renderer> Body<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: iced_core::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    pub fn new(
+        len: usize,
+        columns: usize,
+        row_heights: Option<RowHeights>,
+        overscan: usize,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+        row: impl Fn(usize, Option<(usize, Draft)>) -> Element<'a, Message, Renderer> + 'a,
+    ) -> Self {
+        Self {
+            len,
+            columns,
+            row_heights,
+            overscan,
+            pinned: 0,
+            divider_width: 0.0,
+            row: Box::new(row),
+            row_key: None,
+            selection: None,
+            on_activate: None,
+            on_expand: None,
+            edit: None,
+            matches: vec![],
+            copy: None,
+            paste: None,
+            on_range_select: None,
+            heights: None,
+            style,
+            content: RefCell::new(Content {
+                range: 0..0,
+                element: None,
+                limits: layout::Limits::NONE,
+                layout: layout::Node::new(Size::ZERO),
+            }),
+        }
+    }
+
+    pub fn pinned(self, pinned: usize, divider_width: f32) -> Self {
+        Self {
+            pinned: pinned.min(self.len),
+            divider_width,
+            ..self
+        }
+    }
+
+    pub fn row_key(self, row_key: impl Fn(usize) -> Option<u64> + 'a) -> Self {
+        Self {
+            row_key: Some(Box::new(row_key)),
+            ..self
+        }
+    }
+
+    pub fn selection(self, selection: Selection<'a, Message>) -> Self {
+        Self {
+            selection: Some(selection),
+            ..self
+        }
+    }
+
+    pub fn on_activate(self, on_activate: impl Fn(usize, usize) -> Option<Message> + 'a) -> Self {
+        Self {
+            on_activate: Some(Box::new(on_activate)),
+            ..self
+        }
+    }
+
+    pub fn on_expand(self, on_expand: impl Fn(usize, bool) -> Option<Message> + 'a) -> Self {
+        Self {
+            on_expand: Some(Box::new(on_expand)),
+            ..self
+        }
+    }
+
+    pub fn edit(self, edit: Edit<'a, Message>) -> Self {
+        Self {
+            edit: Some(edit),
+            ..self
+        }
+    }
+
+    pub fn matches(self, matches: Vec<(usize, usize)>) -> Self {
+        Self { matches, ..self }
+    }
+
+    pub fn copy(self, copy: impl Fn(&[usize], Range<usize>) -> String + 'a) -> Self {
+        Self {
+            copy: Some(Box::new(copy)),
+            ..self
+        }
+    }
+
+    pub fn paste(self, paste: impl Fn(usize, usize, String) -> Option<Message> + 'a) -> Self {
+        Self {
+            paste: Some(Box::new(paste)),
+            ..self
+        }
+    }
+
+    pub fn on_range_select(
+        self,
+        on_range_select: impl Fn(Range<usize>, Range<usize>) -> Option<Message> + 'a,
+    ) -> Self {
+        Self {
+            on_range_select: Some(Box::new(on_range_select)),
+            ..self
+        }
+    }
+
+    pub fn heights(self, heights: Heights) -> Self {
+        Self {
+            heights: Some(heights),
+            ..self
+        }
+    }
+
+    // The stable key of the row at `position`, if it has one
+    fn key(&self, position: usize) -> Option<u64> {
+        if position >= self.len {
+            return None;
+        }
+
+        (self.row_key.as_ref()?)(position)
+    }
+
+    fn cell_ref(&self, (position, column): (usize, usize)) -> CellRef {
+        CellRef {
+            position,
+            key: self.key(position),
+            column,
+        }
+    }
+
+    // The cell at the position the row of `cell` has moved to, or `None` if it's been left out.
+    // Cells of rows without a key stay at their position.
+    fn find(&self, cell: CellRef) -> Option<CellRef> {
+        let Some(key) = cell.key else {
+            return Some(cell);
+        };
+
+        if self.key(cell.position) == Some(key) {
+            return Some(cell);
+        }
+
+        (0..self.len)
+            .find(|&position| self.key(position) == Some(key))
+            .map(|position| CellRef { position, ..cell })
+    }
+
+    // The range of rows needed to cover the `viewport`, or `None` if all rows are built.
+    fn visible_range(&self, bounds: Rectangle, viewport: &Rectangle) -> Option<Range<usize>> {
+        let row_heights = self.row_heights.as_ref()?;
+
+        let top = (viewport.y - bounds.y).max(0.0);
+        let bottom = top + viewport.height;
+
+        // Pinned rows are built regardless
+        let start = row_heights.position(top).clamp(self.pinned, self.len);
+        let end = (row_heights.position(bottom) + 1).clamp(start, self.len);
+
+        Some(start..end)
+    }
+
+    fn overscanned(&self, range: Range<usize>) -> Range<usize> {
+        range.start.saturating_sub(self.overscan).max(self.pinned)
+            ..(range.end + self.overscan).min(self.len)
+    }
+
+    fn build(
+        &self,
+        range: Range<usize>,
+        editing: Option<&(CellRef, Draft)>,
+    ) -> Element<'a, Message, Renderer> {
+        // The cell being edited is given its draft
+        let row = |position: usize| {
+            let editing = editing
+                .filter(|(cell, _)| cell.position == position)
+                .map(|(cell, draft)| (cell.column, Rc::clone(draft)));
+
+            (self.row)(position, editing)
+        };
+
+        let pinned = (self.pinned > 0).then(|| {
+            Sticky::new(
+                Column::with_children((0..self.pinned).map(row).collect()),
+                sticky::Edge::Top,
+                || 0.0,
+                Target::PinnedRow { index: 0 },
+                self.style.clone(),
+            )
+            .boundary(self.divider_width)
+            .into()
+        });
+
+        let Some(row_heights) = &self.row_heights else {
+            return Column::with_children(
+                pinned
+                    .into_iter()
+                    .chain((self.pinned..self.len).map(row))
+                    .collect(),
+            )
+            .into();
+        };
+
+        let top = Space::with_height(row_heights.sum(self.pinned..range.start));
+        let bottom = Space::with_height(row_heights.sum(range.end..self.len));
+
+        Column::with_children(
+            pinned
+                .into_iter()
+                .chain(std::iter::once(top.into()))
+                .chain(range.map(row))
+                .chain(std::iter::once(bottom.into()))
+                .collect(),
+        )
+        .into()
+    }
+
+    // Resume from the range built for the previous `Body`, if any
+    fn initial_range(&self, (start, end): (usize, usize)) -> Range<usize> {
+        if self.row_heights.is_none() {
+            return self.pinned..self.len;
+        }
+
+        if start < end {
+            start.clamp(self.pinned, self.len)..end.clamp(self.pinned, self.len)
+        } else {
+            self.pinned..(self.pinned + self.overscan).min(self.len)
+        }
+    }
+
+    // Index of the scrolling row at `position` in the built content, if it's been built
+    fn row_child(
+        &self,
+        content: &Content<'a, Message, Renderer>,
+        position: usize,
+    ) -> Option<usize> {
+        // Skip the pinned rows
+        let pinned = usize::from(self.pinned > 0);
+
+        if self.row_heights.is_some() {
+            // Skip the leading spacer
+            content
+                .range
+                .contains(&position)
+                .then(|| pinned + 1 + position - content.range.start)
+        } else {
+            (self.pinned..self.len)
+                .contains(&position)
+                .then(|| pinned + position - self.pinned)
+        }
+    }
+
+    // Layout, cell trees & shift of the row at `position`, if it's been built
+    fn row_layout<'b>(
+        &self,
+        content: &'b Content<'a, Message, Renderer>,
+        tree: &'b Tree,
+        layout: Layout<'_>,
+        position: usize,
+    ) -> Option<(Layout<'b>, &'b [Tree], Vector)> {
+        if position < self.pinned {
+            let pinned = tree.children.first()?;
+            let rows = content
+                .layout(layout)
+                .children()
+                .next()?
+                .children()
+                .next()?;
+
+            return Some((
+                rows.children().nth(position)?,
+                &pinned.children.first()?.children.get(position)?.children,
+                sticky::shift(pinned),
+            ));
+        }
+
+        let index = self.row_child(content, position)?;
+
+        Some((
+            content.layout(layout).children().nth(index)?,
+            &tree.children.get(index)?.children,
+            Vector::new(0.0, 0.0),
+        ))
+    }
+
+    // Height of the pinned rows, including their divider
+    fn pinned_height(&self, content: &Content<'a, Message, Renderer>, layout: Layout<'_>) -> f32 {
+        if self.pinned == 0 {
+            return 0.0;
+        }
+
+        content
+            .layout(layout)
+            .children()
+            .next()
+            .map_or(0.0, |pinned| pinned.bounds().height)
+    }
+
+    // Position of the row under the cursor
+    fn row_at(
+        &self,
+        content: &Content<'a, Message, Renderer>,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position) {
+            return None;
+        }
+
+        let contains_y = |cursor_position: Point| {
+            move |row: Layout<'_>| {
+                let bounds = row.bounds();
+
+                cursor_position.y >= bounds.y && cursor_position.y < bounds.y + bounds.height
+            }
+        };
+
+        // Pinned rows are drawn above the rows scrolled under them
+        if self.pinned > 0 {
+            let pinned = content
+                .layout(layout)
+                .children()
+                .next()
+                .zip(tree.children.first())
+                .and_then(|(pinned, tree)| {
+                    let cursor_position = cursor_position - sticky::shift(tree);
+
+                    if !pinned.bounds().contains(cursor_position) {
+                        return None;
+                    }
+
+                    pinned
+                        .children()
+                        .next()?
+                        .children()
+                        .position(contains_y(cursor_position))
+                });
+
+            if pinned.is_some() {
+                return pinned;
+            }
+        }
+
+        match &self.row_heights {
+            Some(row_heights) => {
+                let position = row_heights.position(cursor_position.y - bounds.y);
+
+                (position < self.len).then_some(position)
+            }
+            None => content
+                .layout(layout)
+                .children()
+                .skip(usize::from(self.pinned > 0))
+                .position(contains_y(cursor_position))
+                .map(|index| self.pinned + index),
+        }
+    }
+
+    // Column of the cell under the cursor, in the row at `position`
+    fn column_at(
+        &self,
+        content: &Content<'a, Message, Renderer>,
+        tree: &Tree,
+        layout: Layout<'_>,
+        position: usize,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        let (row, cells, shift) = self.row_layout(content, tree, layout, position)?;
+        let bounds = row.bounds();
+
+        // Ignore the vertical position, so the gaps between cells still hit a column
+        sticky::child_at(
+            cells,
+            row.children(),
+            Point::new(cursor_position.x - shift.x, bounds.y + bounds.height / 2.0),
+        )
+    }
+
+    // Bounds of the cell at `position` & `column`.
+    //
+    // The row may not have been built when virtualized, in which case its bounds are derived
+    // from its height and the cell bounds of a row which has been.
+    fn cell_bounds(
+        &self,
+        content: &Content<'a, Message, Renderer>,
+        tree: &Tree,
+        layout: Layout<'_>,
+        (position, column): (usize, usize),
+    ) -> Option<Rectangle> {
+        let cell_bounds = |(row, cells, shift): (Layout<'_>, &[Tree], Vector)| {
+            Some(sticky::visible_bounds(cells.get(column)?, row.children().nth(column)?) + shift)
+        };
+
+        if let Some(row) = self.row_layout(content, tree, layout, position) {
+            return cell_bounds(row);
+        }
+
+        let row_heights = self.row_heights.as_ref()?;
+        let cell = cell_bounds(self.row_layout(content, tree, layout, content.range.start)?)?;
+
+        Some(Rectangle {
+            y: layout.bounds().y + row_heights.top(position),
+            height: row_heights.height(position),
+            ..cell
+        })
+    }
+
+    // Number of rows moved by a page up / down
+    fn page_size(&self, state: &State, content: &Content<'a, Message, Renderer>) -> usize {
+        let Some(viewport) = state.viewport.get() else {
+            return 1;
+        };
+
+        if self.len == 0 {
+            return 1;
+        }
+
+        // Rows are taken to be as tall as the average row
+        let row_height = match &self.row_heights {
+            Some(row_heights) => row_heights.top(self.len) / self.len as f32,
+            None => content.layout.size().height / self.len as f32,
+        };
+
+        if row_height > 0.0 {
+            ((viewport.height / row_height).floor() as usize).max(1)
+        } else {
+            1
+        }
+    }
+
+    // The focus after pressing `key_code`, or `None` if it isn't a navigation key
+    fn navigate(
+        &self,
+        (position, column): (usize, usize),
+        key_code: keyboard::KeyCode,
+        modifiers: keyboard::Modifiers,
+        page: usize,
+    ) -> Option<(usize, usize)> {
+        use keyboard::KeyCode;
+
+        let last_position = self.len - 1;
+        let last_column = self.columns - 1;
+
+        let focus = match key_code {
+            KeyCode::Up => (position.saturating_sub(1), column),
+            KeyCode::Down => ((position + 1).min(last_position), column),
+            KeyCode::Left => (position, column.saturating_sub(1)),
+            KeyCode::Right => (position, (column + 1).min(last_column)),
+            KeyCode::Home if modifiers.command() => (0, column),
+            KeyCode::Home => (position, 0),
+            KeyCode::End if modifiers.command() => (last_position, column),
+            KeyCode::End => (position, last_column),
+            KeyCode::PageUp => (position.saturating_sub(page), column),
+            KeyCode::PageDown => ((position + page).min(last_position), column),
+            KeyCode::Tab if modifiers.shift() => {
+                if column > 0 {
+                    (position, column - 1)
+                } else if position > 0 {
+                    (position - 1, last_column)
+                } else {
+                    (position, column)
+                }
+            }
+            KeyCode::Tab => {
+                if column < last_column {
+                    (position, column + 1)
+                } else if position < last_position {
+                    (position + 1, 0)
+                } else {
+                    (position, column)
+                }
+            }
+            _ => return None,
+        };
+
+        Some(focus)
+    }
+
+    // Focuses the cell at `focus`, and requests it to be scrolled into view
+    fn focus(
+        &self,
+        state: &mut State,
+        content: &Content<'a, Message, Renderer>,
+        layout: Layout<'_>,
+        focus: (usize, usize),
+    ) {
+        let bounds = layout.bounds();
+
+        // Keep scrolling rows clear of the pinned rows drawn above them
+        let pinned_height = if focus.0 < self.pinned {
+            0.0
+        } else {
+            self.pinned_height(content, layout)
+        };
+
+        state.focus = Some(self.cell_ref(focus));
+        state.cells = None;
+        state.scroll_to = self
+            .cell_bounds(content, state.tree.get_mut(), layout, focus)
+            .map(|cell| Rectangle {
+                x: cell.x - bounds.x,
+                y: cell.y - bounds.y - pinned_height,
+                height: cell.height + pinned_height,
+                ..cell
+            });
+    }
+
+    // The match after (or before, when `backwards`) the focused cell, wrapping around
+    fn next_match(&self, focus: Option<(usize, usize)>, backwards: bool) -> Option<(usize, usize)> {
+        let next = focus.and_then(|focus| {
+            if backwards {
+                self.matches.iter().rev().find(|&&cell| cell < focus)
+            } else {
+                self.matches.iter().find(|&&cell| cell > focus)
+            }
+        });
+        let first = if backwards {
+            self.matches.last()
+        } else {
+            self.matches.first()
+        };
+
+        next.or(first).copied()
+    }
+
+    // The selection after clicking the row at `position`
+    fn select(&self, state: &mut State, position: usize) -> Option<Vec<u64>> {
+        let selection = self.selection.as_ref()?;
+        let key = self.key(position)?;
+        let modifiers = state.modifiers;
+        let is_selected = selection.selected.contains(&key);
+
+        let selected = match selection.mode {
+            SelectionMode::None => return None,
+            SelectionMode::Single => {
+                if modifiers.command() && is_selected {
+                    vec![]
+                } else {
+                    vec![key]
+                }
+            }
+            SelectionMode::Multi if modifiers.shift() => {
+                let anchor = state
+                    .anchor
+                    .and_then(|anchor| (0..self.len).find(|&p| self.key(p) == Some(anchor)))
+                    .unwrap_or(position);
+
+                let mut selected = if modifiers.command() {
+                    selection.selected.to_vec()
+                } else {
+                    vec![]
+                };
+                let mut seen: HashSet<u64> = selected.iter().copied().collect();
+
+                let range = anchor.min(position)..=anchor.max(position);
+
+                for key in range.filter_map(|position| self.key(position)) {
+                    if seen.insert(key) {
+                        selected.push(key);
+                    }
+                }
+
+                // Keep the anchor, so the range can be adjusted by another shift-click
+                return Some(selected);
+            }
+            SelectionMode::Multi if modifiers.command() => {
+                if is_selected {
+                    selection
+                        .selected
+                        .iter()
+                        .copied()
+                        .filter(|selected| *selected != key)
+                        .collect()
+                } else {
+                    selection
+                        .selected
+                        .iter()
+                        .copied()
+                        .chain(std::iter::once(key))
+                        .collect()
+                }
+            }
+            SelectionMode::Multi => vec![key],
+        };
+
+        state.anchor = Some(key);
+
+        Some(selected)
+    }
+
+    // The value the cell at `cell` starts with when edited, if it's editable
+    fn edit_value(&self, (position, column): (usize, usize)) -> Option<String> {
+        (self.edit.as_ref()?.value)(position, column)
+    }
+
+    // The editable cell after (or before, when `backwards`) `cell`, along with its value
+    fn next_editable(
+        &self,
+        (position, column): (usize, usize),
+        backwards: bool,
+    ) -> Option<((usize, usize), String)> {
+        if self.columns == 0 {
+            return None;
+        }
+
+        let index = position * self.columns + column;
+        let editable = |index: usize| {
+            let cell = (index / self.columns, index % self.columns);
+
+            self.edit_value(cell).map(|value| (cell, value))
+        };
+
+        if backwards {
+            (0..index).rev().find_map(editable)
+        } else {
+            (index + 1..self.len * self.columns).find_map(editable)
+        }
+    }
+
+    // Stops editing, returning the message committing the edited value
+    fn commit(&self, state: &mut State) -> Option<Message> {
+        let (cell, draft) = state.editing.take()?;
+
+        (self.edit.as_ref()?.on_commit)(cell.position, cell.column, draft.take())
+    }
+
+    // The contents copied from the range of cells dragged across, the selected rows, or the
+    // focused cell if none are selected
+    fn copied(&self, state: &State, (position, column): (usize, usize)) -> Option<String> {
+        let copy = self.copy.as_ref()?;
+
+        if let Some((positions, columns)) = dragged_range(state) {
+            return Some((copy)(&positions.collect::<Vec<_>>(), columns));
+        }
+
+        let selected: Vec<usize> = self
+            .selection
+            .as_ref()
+            .map(|selection| {
+                let keys: HashSet<u64> = selection.selected.iter().copied().collect();
+
+                (0..self.len)
+                    .filter(|&position| self.key(position).map_or(false, |key| keys.contains(&key)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if selected.is_empty() {
+            Some((copy)(&[position], column..column + 1))
+        } else {
+            Some((copy)(&selected, 0..self.columns))
+        }
+    }
+
+    // Rebuilds the rows once the cell being edited, or its value, has changed
+    fn rebuild(&self, state: &mut State, renderer: &Renderer, shell: &mut Shell<'_, Message>) {
+        let mut content = self.content.borrow_mut();
+        let element = self.build(content.range.clone(), state.editing.as_ref());
+
+        state.tree.get_mut().diff(&element);
+        content.layout = element.as_widget().layout(renderer, &content.limits);
+        content.element = Some(element);
+
+        self.record_heights(&content.layout);
+        shell.invalidate_layout();
+    }
+
+    // Records the height of each row once laid out, for the cells spanning several rows.
+    // Every row is built unless virtualized, so this is left to the non-virtualized body.
+    fn record_heights(&self, node: &layout::Node) {
+        let Some(heights) = self.heights.as_ref().filter(|_| self.row_heights.is_none()) else {
+            return;
+        };
+
+        let mut rows = node.children().iter();
+        let pinned = match self.pinned {
+            0 => &[][..],
+            _ => rows
+                .next()
+                .and_then(|sticky| sticky.children().first())
+                .map_or(&[][..], layout::Node::children),
+        };
+
+        *heights.borrow_mut() = pinned
+            .iter()
+            .chain(rows)
+            .map(|row| row.size().height)
+            .collect();
+    }
+
+    // Extends the range of cells being dragged across to the cell nearest the cursor, and
+    // scrolls the body while the cursor nears the edge of the viewport
+    fn drag(
+        &self,
+        state: &mut State,
+        content: &Content<'a, Message, Renderer>,
+        layout: Layout<'_>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Some(((anchor, end), viewport)) = state.cells.zip(state.viewport.get()) else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        // The viewport is only known in the coordinates of the scrolled body
+        let cursor = state.drag_cursor + (viewport.position() - bounds.position());
+
+        // Scrolling rows are kept clear of the pinned rows drawn above them
+        let pinned_height = if anchor.position < self.pinned {
+            0.0
+        } else {
+            self.pinned_height(content, layout)
+        };
+
+        if let Some(area) = viewport.intersection(&bounds) {
+            let nearest = Point::new(
+                cursor.x.min(area.x + area.width - 1.0).max(area.x),
+                cursor.y.min(area.y + area.height - 1.0).max(area.y),
+            );
+
+            let cell = self
+                .row_at(content, state.tree.get_mut(), layout, nearest)
+                .and_then(|position| {
+                    let column =
+                        self.column_at(content, state.tree.get_mut(), layout, position, nearest)?;
+
+                    Some((position, column))
+                });
+
+            if let Some(cell) = cell.filter(|cell| *cell != end.cell()) {
+                state.cells = Some((anchor, self.cell_ref(cell)));
+
+                if let Some(message) = selected_range(state).and_then(|(positions, columns)| {
+                    (self.on_range_select.as_ref()?)(positions, columns)
+                }) {
+                    shell.publish(message);
+                }
+            }
+        }
+
+        let inner = Rectangle {
+            x: viewport.x + AUTO_SCROLL_EDGE,
+            y: viewport.y + pinned_height + AUTO_SCROLL_EDGE,
+            width: (viewport.width - 2.0 * AUTO_SCROLL_EDGE).max(0.0),
+            height: (viewport.height - pinned_height - 2.0 * AUTO_SCROLL_EDGE).max(0.0),
+        };
+
+        if inner.contains(cursor) {
+            return;
+        }
+
+        // Scrolled by at most twice the edge at a time, however far the cursor is past it
+        let x = cursor.x.clamp(
+            viewport.x - AUTO_SCROLL_EDGE,
+            viewport.x + viewport.width + AUTO_SCROLL_EDGE,
+        );
+        let y = cursor.y.clamp(
+            viewport.y - AUTO_SCROLL_EDGE,
+            viewport.y + viewport.height + AUTO_SCROLL_EDGE,
+        );
+
+        state.scroll_to = Some(Rectangle {
+            x: x - bounds.x - AUTO_SCROLL_EDGE,
+            y: y - bounds.y - AUTO_SCROLL_EDGE - pinned_height,
+            width: 2.0 * AUTO_SCROLL_EDGE,
+            height: 2.0 * AUTO_SCROLL_EDGE + pinned_height,
+        });
+
+        // Keep scrolling while the cursor stays put
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
+    // Handles the events ending an edit before the editor gets them, returning the status
+    // if the event shouldn't be passed on
+    fn on_editing_event(
+        &self,
+        state: &mut State,
+        event: &event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) -> Option<event::Status> {
+        let cell = state.editing.as_ref()?.0.cell();
+
+        match event {
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                state.editing = None;
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Enter,
+                ..
+            }) => {
+                if let Some(message) = self.commit(state) {
+                    shell.publish(message);
+                }
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Tab,
+                modifiers,
+            }) => {
+                let next = self.next_editable(cell, modifiers.shift());
+
+                if let Some(message) = self.commit(state) {
+                    shell.publish(message);
+                }
+
+                if let Some((next, value)) = next {
+                    self.focus(state, &self.content.borrow(), layout, next);
+                    state.editing = Some((self.cell_ref(next), Rc::new(RefCell::new(value))));
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(_)) => {
+                let is_editor_hovered = self
+                    .cell_bounds(&self.content.borrow(), state.tree.get_mut(), layout, cell)
+                    .map_or(false, |bounds| bounds.contains(cursor_position));
+
+                if is_editor_hovered {
+                    return None;
+                }
+
+                // Committed once the editor loses focus, while the click is handled as usual
+                if let Some(message) = self.commit(state) {
+                    shell.publish(message);
+                }
+
+                self.rebuild(state, renderer, shell);
+
+                return None;
+            }
+            _ => return None,
+        }
+
+        self.rebuild(state, renderer, shell);
+
+        Some(event::Status::Captured)
+    }
+}
+
+impl<'a, Message, Renderer> Content<'a, Message, Renderer> {
+    fn layout<'b>(&'b self, layout: Layout<'_>) -> Layout<'b> {
+        Layout::with_offset(layout.position() - Point::ORIGIN, &self.layout)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Body<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: iced_core::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            tree: RefCell::new(Tree::empty()),
+            range: Cell::new((0, 0)),
+            viewport: Cell::new(None),
+            modifiers: keyboard::Modifiers::empty(),
+            anchor: None,
+            focus: None,
+            is_focused: false,
+            last_click: None,
+            editing: None,
+            scroll_to: None,
+            cells: None,
+            is_dragging: false,
+            drag_cursor: Point::ORIGIN,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State>();
+        let mut content = self.content.borrow_mut();
+
+        // The rows may have changed since
+        state.focus = state.focus.and_then(|focus| self.find(focus));
+        state.cells = state
+            .cells
+            .and_then(|(anchor, end)| Some((self.find(anchor)?, self.find(end)?)));
+        state.editing = state
+            .editing
+            .take()
+            .and_then(|(cell, draft)| Some((self.find(cell)?, draft)));
+
+        if content.element.is_none() {
+            let range = self.initial_range(state.range.get());
+
+            content.element = Some(self.build(range.clone(), state.editing.as_ref()));
+            content.range = range;
+        }
+
+        if let Some(element) = &content.element {
+            state.tree.get_mut().diff(element);
+        }
+    }
+
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let mut content = self.content.borrow_mut();
+
+        if content.element.is_none() {
+            let range = self.initial_range((0, 0));
+
+            content.element = Some(self.build(range.clone(), None));
+            content.range = range;
+        }
+
+        let limits = limits.width(Length::Shrink).height(Length::Shrink);
+        let node = content
+            .element
+            .as_ref()
+            .map(|element| element.as_widget().layout(renderer, &limits))
+            .unwrap_or_else(|| layout::Node::new(Size::ZERO));
+
+        content.limits = limits;
+        content.layout = node;
+
+        self.record_heights(&content.layout);
+
+        layout::Node::new(content.layout.size())
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if let Some(status) =
+            self.on_editing_event(state, &event, layout, cursor_position, renderer, shell)
+        {
+            return status;
+        }
+
+        let draft = state
+            .editing
+            .as_ref()
+            .map(|(_, draft)| draft.borrow().clone());
+
+        let content = self.content.get_mut();
+        let content_layout =
+            Layout::with_offset(layout.position() - Point::ORIGIN, &content.layout);
+
+        let Some(element) = content.element.as_mut() else {
+            return event::Status::Ignored;
+        };
+
+        let status = element.as_widget_mut().on_event(
+            state.tree.get_mut(),
+            event.clone(),
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let is_edited = state.editing.as_ref().map_or(false, |(_, edited)| {
+            draft.as_ref() != Some(&*edited.borrow())
+        });
+
+        // Show the value written by the editor
+        if is_edited {
+            self.rebuild(state, renderer, shell);
+        }
+
+        let content = self.content.borrow();
+
+        match event {
+            event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                let position = self.row_at(&content, state.tree.get_mut(), layout, cursor_position);
+
+                state.is_focused = position.is_some();
+
+                let Some(position) = position else {
+                    return status;
+                };
+
+                state.cells = None;
+
+                if let Some(column) = self.column_at(
+                    &content,
+                    state.tree.get_mut(),
+                    layout,
+                    position,
+                    cursor_position,
+                ) {
+                    state.focus = Some(self.cell_ref((position, column)));
+
+                    if self.on_range_select.is_some() && status == event::Status::Ignored {
+                        let offset = state
+                            .viewport
+                            .get()
+                            .map_or(Vector::new(0.0, 0.0), |viewport| {
+                                viewport.position() - layout.bounds().position()
+                            });
+
+                        let cell = self.cell_ref((position, column));
+
+                        state.cells = Some((cell, cell));
+                        state.is_dragging = true;
+                        state.drag_cursor = cursor_position - offset;
+                    }
+                }
+
+                let click = mouse::Click::new(cursor_position, state.last_click);
+
+                state.last_click = Some(click);
+
+                if click.kind() == mouse::click::Kind::Double && status == event::Status::Ignored {
+                    let editable = state
+                        .focus
+                        .map(CellRef::cell)
+                        .filter(|(focused, _)| *focused == position)
+                        .and_then(|cell| Some((cell, self.edit_value(cell)?)));
+
+                    if let Some((cell, value)) = editable {
+                        state.editing = Some((self.cell_ref(cell), Rc::new(RefCell::new(value))));
+
+                        drop(content);
+                        self.rebuild(state, renderer, shell);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if status == event::Status::Ignored {
+                    if let Some(selected) = self.select(state, position) {
+                        if let Some(selection) = &self.selection {
+                            shell.publish((selection.on_change)(selected));
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                state.is_dragging = false;
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { position }) if state.is_dragging => {
+                state.drag_cursor = position;
+                self.drag(state, &content, layout, shell);
+
+                return event::Status::Captured;
+            }
+            event::Event::Window(window::Event::RedrawRequested(_)) if state.is_dragging => {
+                self.drag(state, &content, layout, shell);
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::F3,
+                modifiers,
+            }) if !self.matches.is_empty() => {
+                if let Some(focus) =
+                    self.next_match(state.focus.map(CellRef::cell), modifiers.shift())
+                {
+                    state.is_focused = true;
+                    self.focus(state, &content, layout, focus);
+                }
+
+                return event::Status::Captured;
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if state.is_focused
+                && state.editing.is_none()
+                && status == event::Status::Ignored
+                && self.len > 0
+                && self.columns > 0 =>
+            {
+                let focus = state
+                    .focus
+                    .map(CellRef::cell)
+                    .map_or((0, 0), |(position, column)| {
+                        (position.min(self.len - 1), column.min(self.columns - 1))
+                    });
+
+                if key_code == keyboard::KeyCode::C && modifiers.command() {
+                    if let Some(contents) = self.copied(state, focus) {
+                        clipboard.write(contents);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if key_code == keyboard::KeyCode::V && modifiers.command() {
+                    if let Some(paste) = &self.paste {
+                        if let Some(message) = clipboard
+                            .read()
+                            .and_then(|text| (paste)(focus.0, focus.1, text))
+                        {
+                            shell.publish(message);
+                        }
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if key_code == keyboard::KeyCode::Enter {
+                    if let Some(value) = self.edit_value(focus) {
+                        state.editing = Some((self.cell_ref(focus), Rc::new(RefCell::new(value))));
+
+                        drop(content);
+                        self.rebuild(state, renderer, shell);
+
+                        return event::Status::Captured;
+                    }
+
+                    if let Some(message) = self
+                        .on_activate
+                        .as_ref()
+                        .and_then(|on_activate| (on_activate)(focus.0, focus.1))
+                    {
+                        shell.publish(message);
+                    }
+
+                    return event::Status::Captured;
+                }
+
+                if let Some(message) =
+                    self.on_expand
+                        .as_ref()
+                        .and_then(|on_expand| match key_code {
+                            keyboard::KeyCode::Left => (on_expand)(focus.0, false),
+                            keyboard::KeyCode::Right => (on_expand)(focus.0, true),
+                            _ => None,
+                        })
+                {
+                    shell.publish(message);
+
+                    return event::Status::Captured;
+                }
+
+                let page = self.page_size(state, &content);
+
+                if let Some(focus) = self.navigate(focus, key_code, modifiers, page) {
+                    self.focus(state, &content, layout, focus);
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let content = self.content.borrow();
+
+        content
+            .element
+            .as_ref()
+            .map(|element| {
+                element.as_widget().mouse_interaction(
+                    &state.tree.borrow(),
+                    content.layout(layout),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .unwrap_or(mouse::Interaction::Idle)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut content = self.content.borrow_mut();
+
+        // Rebuild the rows once the viewport leaves the range we've built
+        if let Some(visible) = self.visible_range(layout.bounds(), viewport) {
+            if visible.start < content.range.start || visible.end > content.range.end {
+                let range = self.overscanned(visible);
+                let element = self.build(range.clone(), state.editing.as_ref());
+
+                state.tree.borrow_mut().diff(&element);
+                content.layout = element.as_widget().layout(renderer, &content.limits);
+                content.element = Some(element);
+                content.range = range;
+            }
+        }
+
+        state.range.set((content.range.start, content.range.end));
+        state.viewport.set(Some(*viewport));
+
+        if let Some(element) = &content.element {
+            element.as_widget().draw(
+                &state.tree.borrow(),
+                renderer,
+                theme,
+                style,
+                content.layout(layout),
+                cursor_position,
+                viewport,
+            );
+        }
+
+        if let Some((positions, columns)) = dragged_range(state) {
+            let tree = state.tree.borrow();
+            let first = (positions.start, columns.start);
+            let last = (positions.end - 1, columns.end - 1);
+            let first = self.cell_bounds(&content, &tree, layout, first);
+            let last = self.cell_bounds(&content, &tree, layout, last);
+
+            if let Some((first, last)) = first.zip(last) {
+                let x = first.x.min(last.x);
+                let y = first.y.min(last.y);
+                let bounds = Rectangle {
+                    x,
+                    y,
+                    width: (first.x + first.width).max(last.x + last.width) - x,
+                    height: (first.y + first.height).max(last.y + last.height) - y,
+                };
+
+                fill_layer(renderer, *viewport, bounds, theme.selection(&self.style));
+            }
+        }
+
+        if let Some(bounds) = state
+            .focus
+            .map(CellRef::cell)
+            .filter(|_| state.is_focused)
+            .and_then(|focus| self.cell_bounds(&content, &state.tree.borrow(), layout, focus))
+        {
+            fill_layer(renderer, *viewport, bounds, theme.focused_cell(&self.style));
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let content = self.content.borrow();
+
+        if let Some(element) = &content.element {
+            element.as_widget().operate(
+                state.tree.get_mut(),
+                content.layout(layout),
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let state = tree.state.downcast_mut::<State>();
+        let Content {
+            element,
+            layout: node,
+            ..
+        } = self.content.get_mut();
+
+        element.as_mut()?.as_widget_mut().overlay(
+            state.tree.get_mut(),
+            Layout::with_offset(layout.position() - Point::ORIGIN, node),
+            renderer,
+        )
+    }
+}
+
+// Positions & columns of the range of cells dragged across, if any
+fn selected_range(state: &State) -> Option<(Range<usize>, Range<usize>)> {
+    let (start, end) = state.cells?;
+    let ((start_position, start_column), (end_position, end_column)) = (start.cell(), end.cell());
+
+    Some((
+        start_position.min(end_position)..start_position.max(end_position) + 1,
+        start_column.min(end_column)..start_column.max(end_column) + 1,
+    ))
+}
+
+// The range of cells dragged across, once dragged past the cell it started from, which is
+// left to the focus otherwise
+fn dragged_range(state: &State) -> Option<(Range<usize>, Range<usize>)> {
+    selected_range(state).filter(|(positions, columns)| positions.len() > 1 || columns.len() > 1)
+}
+
+// Fills `bounds` in its own layer, to be drawn above any pinned cells
+fn fill_layer<Renderer: renderer::Renderer>(
+    renderer: &mut Renderer,
+    viewport: Rectangle,
+    bounds: Rectangle,
+    appearance: container::Appearance,
+) {
+    renderer.with_layer(viewport, |renderer| {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+    });
+}
+
+impl<'a, Message, Renderer> From<Body<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: iced_core::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn from(body: Body<'a, Message, Renderer>) -> Self {
+        Element::new(body)
+    }
+}
@@ -8,9 +8,25 @@ use crate::style::{self, StyleSheet};
 #[derive(Clone, Copy, Debug, Default)]
 struct State {
     drag_origin: Option<Point>,
+    offset: f32,
     is_divider_hovered: bool,
+    last_click: Option<mouse::Click>,
 }
 
+/// The edge of its content a [`Divider`] is on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Edge {
+    /// The right edge of a cell, resizing its column.
+    Right,
+    /// The bottom edge of a row, resizing the row.
+    Bottom,
+}
+
+/// A divider on the right edge of a cell, which can be dragged to resize its column, or on
+/// the bottom edge of a row to resize the row.
+///
+/// Each callback may return no message, when it resizes the column itself. The layout is
+/// invalidated after any of them is called.
 pub(crate) struct Divider<'a, Message, Renderer>
 where
     Renderer: renderer::Renderer,
@@ -18,8 +34,11 @@ where
 {
     content: Element<'a, Message, Renderer>,
     width: f32,
-    on_drag: Box<dyn Fn(f32) -> Message + 'a>,
-    on_release: Message,
+    edge: Edge,
+    on_drag: Box<dyn Fn(f32) -> Option<Message> + 'a>,
+    on_release: Box<dyn Fn() -> Option<Message> + 'a>,
+    on_double_click: Option<Box<dyn Fn() -> Option<Message> + 'a>>,
+    range: (f32, f32),
     style: <Renderer::Theme as style::StyleSheet>::Style,
 }
 
@@ -31,39 +50,84 @@ where
     pub fn new(
         content: impl Into<Element<'a, Message, Renderer>>,
         width: f32,
-        on_drag: impl Fn(f32) -> Message + 'a,
-        on_release: Message,
+        on_drag: impl Fn(f32) -> Option<Message> + 'a,
+        on_release: impl Fn() -> Option<Message> + 'a,
         style: <Renderer::Theme as style::StyleSheet>::Style,
     ) -> Self {
         Self {
             content: content.into(),
             width,
+            edge: Edge::Right,
             on_drag: Box::new(on_drag),
-            on_release,
+            on_release: Box::new(on_release),
+            on_double_click: None,
+            range: (f32::NEG_INFINITY, f32::INFINITY),
             style,
         }
     }
 
+    pub fn on_double_click(self, on_double_click: impl Fn() -> Option<Message> + 'a) -> Self {
+        Self {
+            on_double_click: Some(Box::new(on_double_click)),
+            ..self
+        }
+    }
+
+    /// Moves the divider to the bottom edge, overlapping the content rather than adding its
+    /// width to it.
+    pub fn bottom(self) -> Self {
+        Self {
+            edge: Edge::Bottom,
+            ..self
+        }
+    }
+
+    /// Limits the offset emitted by `on_drag` to `min..=max`, so the divider stops there.
+    pub fn range(self, min: f32, max: f32) -> Self {
+        Self {
+            range: (min, max),
+            ..self
+        }
+    }
+
     fn divider_bounds(&self, bounds: Rectangle) -> Rectangle {
-        Rectangle {
-            x: bounds.x + bounds.width - self.width,
-            width: self.width,
-            ..bounds
+        match self.edge {
+            Edge::Right => Rectangle {
+                x: bounds.x + bounds.width - self.width,
+                width: self.width,
+                ..bounds
+            },
+            Edge::Bottom => Rectangle {
+                y: bounds.y + bounds.height - self.width,
+                height: self.width,
+                ..bounds
+            },
         }
     }
 
     fn is_divider_hovered(&self, bounds: Rectangle, cursor_position: Point) -> bool {
         let mut bounds = self.divider_bounds(bounds);
         // TODO: Configurable
-        bounds.x -= 5.0;
-        bounds.width += 10.0;
+        match self.edge {
+            Edge::Right => {
+                bounds.x -= 5.0;
+                bounds.width += 10.0;
+            }
+            Edge::Bottom => {
+                bounds.y -= 5.0;
+                bounds.height += 10.0;
+            }
+        }
 
         bounds.contains(cursor_position)
     }
 
     fn is_content_hovered(&self, mut bounds: Rectangle, cursor_position: Point) -> bool {
-        // Ignore left edge to not conflict with other dividers
-        bounds.x += (bounds.width - 5.0).clamp(0.0, 5.0);
+        // Ignore left / top edge to not conflict with other dividers
+        match self.edge {
+            Edge::Right => bounds.x += (bounds.width - 5.0).clamp(0.0, 5.0),
+            Edge::Bottom => bounds.y += (bounds.height - 5.0).clamp(0.0, 5.0),
+        }
         bounds.contains(cursor_position)
     }
 }
@@ -91,7 +155,10 @@ where
     }
 
     fn width(&self) -> Length {
-        Length::Fill
+        match self.edge {
+            Edge::Right => Length::Fill,
+            Edge::Bottom => self.content.as_widget().width(),
+        }
     }
 
     fn height(&self) -> Length {
@@ -99,10 +166,13 @@ where
     }
 
     fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
-        let padding = [0.0, self.width, 0.0, 0.0].into();
+        let padding = match self.edge {
+            Edge::Right => [0.0, self.width, 0.0, 0.0].into(),
+            Edge::Bottom => [0.0, 0.0, 0.0, 0.0].into(),
+        };
 
         let limits = limits
-            .width(Length::Fill)
+            .width(self.width())
             .height(Length::Shrink)
             .pad(padding);
 
@@ -129,19 +199,46 @@ where
             match event {
                 mouse::Event::ButtonPressed(mouse::Button::Left) => {
                     if state.is_divider_hovered {
+                        let click = mouse::Click::new(cursor_position, state.last_click);
+
+                        state.last_click = Some(click);
+
+                        if let Some(on_double_click) = self
+                            .on_double_click
+                            .as_ref()
+                            .filter(|_| click.kind() == mouse::click::Kind::Double)
+                        {
+                            publish(shell, (on_double_click)());
+                            return event::Status::Captured;
+                        }
+
                         state.drag_origin = Some(cursor_position);
+                        state.offset = 0.0;
                         return event::Status::Captured;
                     }
                 }
                 mouse::Event::ButtonReleased(mouse::Button::Left) => {
                     if state.drag_origin.take().is_some() {
-                        shell.publish(self.on_release.clone());
+                        publish(shell, (self.on_release)());
                         return event::Status::Captured;
                     }
                 }
                 mouse::Event::CursorMoved { position } => {
                     if let Some(origin) = state.drag_origin {
-                        shell.publish((self.on_drag)((position - origin).x));
+                        let (min, max) = self.range;
+                        let offset = match self.edge {
+                            Edge::Right => position.x - origin.x,
+                            Edge::Bottom => position.y - origin.y,
+                        }
+                        .min(max)
+                        .max(min);
+
+                        // Past either bound, the cursor no longer moves the divider
+                        if offset != state.offset {
+                            state.offset = offset;
+                            publish(shell, (self.on_drag)(offset));
+                        }
+
                         return event::Status::Captured;
                     }
                 }
@@ -171,7 +268,10 @@ where
         let state = tree.state.downcast_ref::<State>();
 
         if state.drag_origin.is_some() || state.is_divider_hovered {
-            mouse::Interaction::ResizingHorizontally
+            match self.edge {
+                Edge::Right => mouse::Interaction::ResizingHorizontally,
+                Edge::Bottom => mouse::Interaction::ResizingVertically,
+            }
         } else {
             self.content.as_widget().mouse_interaction(
                 &tree.children[0],
@@ -214,15 +314,17 @@ where
                 state.is_divider_hovered || state.drag_origin.is_some(),
             );
 
-            let snap = |bounds: Rectangle| {
-                let position = bounds.position();
-
-                Rectangle {
-                    x: position.x.floor(),
-                    y: position.y,
+            let snap = |bounds: Rectangle| match self.edge {
+                Edge::Right => Rectangle {
+                    x: bounds.x.floor(),
                     width: self.width,
                     ..bounds
-                }
+                },
+                Edge::Bottom => Rectangle {
+                    y: bounds.y.floor(),
+                    height: self.width,
+                    ..bounds
+                },
             };
 
             renderer.fill_quad(
@@ -268,6 +370,15 @@ where
     }
 }
 
+// The column may have been resized without a message
+fn publish<Message>(shell: &mut Shell<'_, Message>, message: Option<Message>) {
+    if let Some(message) = message {
+        shell.publish(message);
+    }
+
+    shell.invalidate_layout();
+}
+
 impl<'a, Message, Renderer> From<Divider<'a, Message, Renderer>> for Element<'a, Message, Renderer>
 where
     Message: Clone + 'a,
@@ -0,0 +1,349 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, operation, Tree, Widget};
+use iced_core::{event, mouse, overlay, Element, Length, Point, Rectangle, Size};
+use iced_core::{renderer, Clipboard, Shell};
+
+/// The value of the cell being edited, shared between a [`Body`](crate::body::Body) and the
+/// [`Editor`] of that cell.
+pub(crate) type Draft = Rc<RefCell<String>>;
+
+#[derive(Debug, Default)]
+struct State {
+    is_focused: bool,
+}
+
+/// The editor of a cell, producing the edited value as its messages.
+///
+/// Rather than being published, the last value produced, by the content or its overlay, is
+/// written to the [`Draft`].
+/// The editor is focused as soon as it receives its first event.
+pub(crate) struct Editor<'a, Message, Renderer> {
+    content: Element<'a, String, Renderer>,
+    draft: Draft,
+    message: PhantomData<Message>,
+}
+
+impl<'a, Message, Renderer> Editor<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(content: impl Into<Element<'a, String, Renderer>>, draft: Draft) -> Self {
+        Self {
+            content: content.into(),
+            draft,
+            message: PhantomData,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Editor<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        if !state.is_focused {
+            state.is_focused = true;
+
+            self.content
+                .as_widget()
+                .operate(&mut tree.children[0], layout, renderer, &mut Focus);
+        }
+
+        write_draft(&self.draft, shell, |editor_shell| {
+            self.content.as_widget_mut().on_event(
+                &mut tree.children[0],
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                editor_shell,
+            )
+        })
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout,
+            renderer,
+            &mut MapOperation { operation },
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let content =
+            self.content
+                .as_widget_mut()
+                .overlay(&mut tree.children[0], layout, renderer)?;
+
+        // Laid out at the position of the content's overlay, moved along with this one
+        Some(overlay::Element::new(
+            Point::ORIGIN,
+            Box::new(Overlay {
+                content,
+                draft: Rc::clone(&self.draft),
+            }),
+        ))
+    }
+}
+
+// Runs `on_event` with a shell of its own, passing its requests on to `shell` and writing the
+// last value produced to the `draft`
+fn write_draft<Message>(
+    draft: &Draft,
+    shell: &mut Shell<'_, Message>,
+    on_event: impl FnOnce(&mut Shell<'_, String>) -> event::Status,
+) -> event::Status {
+    let mut values = vec![];
+    let mut editor_shell = Shell::new(&mut values);
+
+    let status = on_event(&mut editor_shell);
+
+    if let Some(request) = editor_shell.redraw_request() {
+        shell.request_redraw(request);
+    }
+
+    if editor_shell.is_layout_invalid() {
+        shell.invalidate_layout();
+    }
+
+    if editor_shell.are_widgets_invalid() {
+        shell.invalidate_widgets();
+    }
+
+    if let Some(value) = values.pop() {
+        *draft.borrow_mut() = value;
+    }
+
+    status
+}
+
+// The overlay of the content of an editor, such as the menu of a pick list, writing the values
+// it produces to the draft as well
+struct Overlay<'b, Renderer> {
+    content: overlay::Element<'b, String, Renderer>,
+    draft: Draft,
+}
+
+impl<'b, Message, Renderer> overlay::Overlay<Message, Renderer> for Overlay<'b, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        self.content
+            .layout(renderer, bounds, position - Point::ORIGIN)
+    }
+
+    fn on_event(
+        &mut self,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        write_draft(&self.draft, shell, |editor_shell| {
+            self.content.on_event(
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                editor_shell,
+            )
+        })
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .mouse_interaction(layout, cursor_position, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content
+            .draw(renderer, theme, style, layout, cursor_position);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content
+            .operate(layout, renderer, &mut MapOperation { operation });
+    }
+}
+
+// Passes an operation on the widgets of the editor's owner to the content of the editor, which
+// produces values instead of messages
+struct MapOperation<'a, Message> {
+    operation: &'a mut dyn widget::Operation<Message>,
+}
+
+impl<'a, Message> widget::Operation<String> for MapOperation<'a, Message> {
+    fn container(
+        &mut self,
+        id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn widget::Operation<String>),
+    ) {
+        self.operation.container(id, &mut |operation| {
+            operate_on_children(&mut MapOperation { operation });
+        });
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, id: Option<&widget::Id>) {
+        self.operation.focusable(state, id);
+    }
+
+    fn scrollable(&mut self, state: &mut dyn operation::Scrollable, id: Option<&widget::Id>) {
+        self.operation.scrollable(state, id);
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, id: Option<&widget::Id>) {
+        self.operation.text_input(state, id);
+    }
+
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&widget::Id>) {
+        self.operation.custom(state, id);
+    }
+}
+
+// Focuses every focusable widget, with the cursor of text inputs at the end
+struct Focus;
+
+impl<T> widget::Operation<T> for Focus {
+    fn container(
+        &mut self,
+        _id: Option<&widget::Id>,
+        operate_on_children: &mut dyn FnMut(&mut dyn widget::Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn operation::Focusable, _id: Option<&widget::Id>) {
+        state.focus();
+    }
+
+    fn text_input(&mut self, state: &mut dyn operation::TextInput, _id: Option<&widget::Id>) {
+        state.move_cursor_to_end();
+    }
+}
+
+impl<'a, Message, Renderer> From<Editor<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(editor: Editor<'a, Message, Renderer>) -> Self {
+        Element::new(editor)
+    }
+}
@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, mouse, overlay, Element, Length, Point, Rectangle, Size};
+use iced_core::{renderer, Clipboard, Shell};
+use iced_widget::scrollable;
+
+use crate::body;
+use crate::sizing::{Measured, Resized, Widths};
+
+// Position of each scrollable within the frame's column
+const HEADER: usize = 0;
+const BODY: usize = 1;
+const FOOTER: usize = 2;
+
+/// The outer widget of a [`Table`](crate::Table), holding the header, body & footer
+/// scrollables in a column.
+///
+/// The header & footer are kept horizontally locked to the body by writing the body's
+/// offset straight into their scrollable state, as soon as the body has handled an event.
+/// Likewise, the body is scrolled to any bounds its content requested to bring into view.
+///
+/// The column [`Widths`] are resolved as the frame is laid out, measuring the cells first
+/// if needed. Measured widths are kept in the frame's state, to be reused by the next frame,
+/// along with the widths of the columns resized by the table itself.
+pub(crate) struct Frame<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    widths: Rc<Widths>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    measured: RefCell<Option<Measured>>,
+    resized: Resized,
+}
+
+impl<'a, Message, Renderer> Frame<'a, Message, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    pub fn new(content: impl Into<Element<'a, Message, Renderer>>, widths: Rc<Widths>) -> Self {
+        Self {
+            content: content.into(),
+            widths,
+        }
+    }
+
+    fn sync(tree: &mut Tree, layout: Layout<'_>) {
+        let Some(body_layout) = layout.children().nth(BODY) else {
+            return;
+        };
+        let Some(body_content) = body_layout.children().next() else {
+            return;
+        };
+
+        let body_tree = &mut tree.children[BODY];
+        let scroll_to = body_tree
+            .children
+            .get_mut(0)
+            .and_then(body::take_scroll_request);
+        let body = body_tree.state.downcast_mut::<scrollable::State>();
+        let bounds = body_layout.bounds();
+
+        if let Some(target) = scroll_to {
+            let offset = body.offset(bounds, body_content.bounds());
+
+            // Scroll the least amount needed for `target` to be fully visible
+            let into_view = |target: f32, size: f32, offset: f32, viewport: f32| {
+                if target < offset {
+                    target
+                } else if target + size > offset + viewport {
+                    target + size - viewport
+                } else {
+                    offset
+                }
+            };
+
+            body.scroll_to(scrollable::AbsoluteOffset {
+                x: into_view(target.x, target.width, offset.x, bounds.width).max(0.0),
+                y: into_view(target.y, target.height, offset.y, bounds.height).max(0.0),
+            });
+        }
+
+        let offset = body.offset(bounds, body_content.bounds());
+
+        for index in [HEADER, FOOTER] {
+            if let Some(tree) = tree.children.get_mut(index) {
+                tree.state.downcast_mut::<scrollable::State>().scroll_to(
+                    scrollable::AbsoluteOffset {
+                        x: offset.x,
+                        y: 0.0,
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Frame<'a, Message, Renderer>
+where
+    Renderer: iced_core::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_ref::<State>();
+
+        if let Some(measured) = &*state.measured.borrow() {
+            self.widths.restore(measured);
+        }
+
+        self.widths.restore_resized(&state.resized);
+
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        if self.widths.needs_measuring() {
+            self.widths.measure();
+
+            let _ = self.content.as_widget().layout(renderer, limits);
+
+            self.widths.resolve();
+        }
+
+        let content = self.content.as_widget().layout(renderer, limits);
+        let size = content.size();
+
+        // Left empty when no column fills the minimum width of the table
+        layout::Node::with_children(
+            Size::new(size.width + self.widths.unused(), size.height),
+            vec![content],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let content_layout = layout.children().next().unwrap();
+
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            content_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        Self::sync(&mut tree.children[0], content_layout);
+
+        // Kept right away, as a message published alongside may rebuild the table before
+        // the next draw
+        tree.state.downcast_mut::<State>().resized = self.widths.resized();
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        // Keep what was measured while laying out, for the next frame
+        if let Some(measured) = self.widths.measured() {
+            tree.state
+                .downcast_ref::<State>()
+                .measured
+                .replace(Some(measured));
+        }
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        let content_layout = layout.children().next().unwrap();
+
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            content_layout,
+            renderer,
+            operation,
+        );
+
+        // The body may have been scrolled through its `Id`
+        Self::sync(&mut tree.children[0], content_layout);
+    }
+}
+
+impl<'a, Message, Renderer> From<Frame<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: iced_core::Renderer + 'a,
+{
+    fn from(frame: Frame<'a, Message, Renderer>) -> Self {
+        Element::new(frame)
+    }
+}
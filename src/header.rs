@@ -0,0 +1,431 @@
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, keyboard, mouse, overlay, Color, Element, Length, Point, Rectangle};
+use iced_core::{renderer, Clipboard, Shell, Vector};
+use iced_widget::container;
+
+use crate::menu::Menu;
+use crate::sticky;
+use crate::style::{self, StyleSheet};
+
+// Distance the cursor must travel before a press becomes a drag
+const DRAG_THRESHOLD: f32 = 4.0;
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    pressed: Option<usize>,
+    origin: Point,
+    // Last position of the cursor, kept once it leaves the header's scrollable, which only
+    // passes it on while hovered
+    cursor: Point,
+    // From window coordinates to those of the header, as of the last time it was hovered
+    offset: Vector,
+    is_dragging: bool,
+    modifiers: keyboard::Modifiers,
+    menu: Option<Point>,
+}
+
+/// The row of header cells of a [`Table`](crate::Table).
+///
+/// Clicking a header cell emits the message returned by `on_click` for that column, unless
+/// the press was captured by the cell itself (e.g. by its [`Divider`](crate::divider::Divider)).
+///
+/// When `on_reorder` is set, a header cell can instead be dragged to another position. A
+/// preview of the cell follows the cursor, and a marker is drawn where it would be inserted.
+/// The cursor is followed through its window position while dragged, as the scrollable
+/// holding the header stops passing it on once it leaves.
+///
+/// When a `menu` is set, right-clicking the header opens it as a [`Menu`] at the cursor.
+pub(crate) struct Header<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    on_click: Box<dyn Fn(usize, keyboard::Modifiers) -> Option<Message> + 'a>,
+    on_reorder: Option<Box<dyn Fn(usize, usize) -> Message + 'a>>,
+    marker_width: f32,
+    menu: Option<Element<'a, Message, Renderer>>,
+    style: <Renderer::Theme as style::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Header<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+        on_click: impl Fn(usize, keyboard::Modifiers) -> Option<Message> + 'a,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_click: Box::new(on_click),
+            on_reorder: None,
+            marker_width: 0.0,
+            menu: None,
+            style,
+        }
+    }
+
+    /// `on_reorder` receives the index of the dragged column and the index it's moved to,
+    /// once the other columns have shifted to make room for it.
+    pub fn on_reorder(
+        self,
+        on_reorder: impl Fn(usize, usize) -> Message + 'a,
+        marker_width: f32,
+    ) -> Self {
+        Self {
+            on_reorder: Some(Box::new(on_reorder)),
+            marker_width,
+            ..self
+        }
+    }
+
+    pub fn menu(self, menu: impl Into<Element<'a, Message, Renderer>>) -> Self {
+        Self {
+            menu: Some(menu.into()),
+            ..self
+        }
+    }
+
+    fn hovered_cell(tree: &Tree, layout: Layout<'_>, cursor_position: Point) -> Option<usize> {
+        sticky::child_at(
+            &tree.children[0].children,
+            layout.children().next()?.children(),
+            cursor_position,
+        )
+    }
+
+    // Bounds of each cell, as drawn
+    fn cell_bounds(tree: &Tree, layout: Layout<'_>) -> Vec<Rectangle> {
+        let Some(row) = layout.children().next() else {
+            return vec![];
+        };
+
+        tree.children[0]
+            .children
+            .iter()
+            .zip(row.children())
+            .map(|(tree, layout)| sticky::visible_bounds(tree, layout))
+            .collect()
+    }
+
+    // The gap a dragged cell would be inserted into, being the index of the cell after it
+    fn insertion_gap(cells: &[Rectangle], cursor_position: Point) -> usize {
+        cells
+            .iter()
+            .filter(|bounds| bounds.center_x() < cursor_position.x)
+            .count()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Header<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            pressed: None,
+            origin: Point::ORIGIN,
+            cursor: Point::ORIGIN,
+            offset: Vector::new(0.0, 0.0),
+            is_dragging: false,
+            modifiers: keyboard::Modifiers::empty(),
+            menu: None,
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        std::iter::once(&self.content)
+            .chain(&self.menu)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = std::iter::once(&self.content).chain(&self.menu).collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        let hovered = Self::hovered_cell(tree, layout, cursor_position);
+        let cells = Self::cell_bounds(tree, layout);
+        let state = tree.state.downcast_mut::<State>();
+
+        if let event::Event::Mouse(mouse::Event::CursorMoved { position }) = event {
+            if layout.bounds().contains(cursor_position) {
+                state.offset = cursor_position - position;
+            }
+
+            state.cursor = position + state.offset;
+        }
+
+        match event {
+            event::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                state.modifiers = modifiers;
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if status == event::Status::Ignored =>
+            {
+                state.pressed = hovered;
+                state.origin = cursor_position;
+                state.cursor = cursor_position;
+                state.is_dragging = false;
+
+                if state.pressed.is_some() {
+                    return event::Status::Captured;
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if status == event::Status::Ignored
+                    && self.menu.is_some()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                state.menu = Some(cursor_position);
+                return event::Status::Captured;
+            }
+            event::Event::Mouse(mouse::Event::CursorMoved { .. })
+                if state.pressed.is_some() && self.on_reorder.is_some() =>
+            {
+                if (state.cursor.x - state.origin.x).abs() > DRAG_THRESHOLD {
+                    state.is_dragging = true;
+                }
+
+                if state.is_dragging {
+                    return event::Status::Captured;
+                }
+            }
+            event::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                if let Some(pressed) = state.pressed.take() {
+                    if std::mem::take(&mut state.is_dragging) {
+                        let gap = Self::insertion_gap(&cells, state.cursor);
+                        // The columns after the dragged one shift left once it's removed
+                        let to = if gap > pressed { gap - 1 } else { gap };
+
+                        if let Some(on_reorder) = &self.on_reorder {
+                            if to != pressed {
+                                shell.publish((on_reorder)(pressed, to));
+                            }
+                        }
+                    } else if hovered == Some(pressed) {
+                        if let Some(message) = (self.on_click)(pressed, state.modifiers) {
+                            shell.publish(message);
+                        }
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if tree.state.downcast_ref::<State>().is_dragging {
+            return mouse::Interaction::Grabbing;
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let content_layout = layout.children().next().unwrap();
+
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        let Some(pressed) = state.pressed.filter(|_| state.is_dragging) else {
+            return;
+        };
+
+        let cells = Self::cell_bounds(tree, layout);
+        let Some(cell) = cells.get(pressed) else {
+            return;
+        };
+
+        let bounds = layout.bounds();
+        let translation = Vector::new(state.cursor.x - state.origin.x, 0.0);
+        let preview = *cell + translation;
+
+        // Marker on the leading edge of the cell after the gap, or the end of the last cell
+        let gap = Self::insertion_gap(&cells, state.cursor);
+        let marker = cells
+            .get(gap)
+            .map(|cell| cell.x)
+            .or_else(|| cells.last().map(|cell| cell.x + cell.width))
+            .unwrap_or(bounds.x);
+
+        let fill =
+            |renderer: &mut Renderer, bounds: Rectangle, appearance: container::Appearance| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance
+                        .background
+                        .unwrap_or_else(|| Color::TRANSPARENT.into()),
+                );
+            };
+
+        // Above the header, including any pinned cells
+        renderer.with_layer(*viewport, |renderer| {
+            fill(
+                renderer,
+                Rectangle {
+                    x: (marker - self.marker_width / 2.0).floor(),
+                    width: self.marker_width,
+                    ..bounds
+                },
+                theme.divider(&self.style, true),
+            );
+
+            // The preview is the cell itself, moved along with the cursor
+            renderer.with_layer(preview, |renderer| {
+                fill(renderer, preview, theme.header(&self.style));
+
+                renderer.with_translation(translation, |renderer| {
+                    self.content.as_widget().draw(
+                        &tree.children[0],
+                        renderer,
+                        theme,
+                        style,
+                        content_layout,
+                        cursor_position - translation,
+                        viewport,
+                    );
+                });
+
+                fill(renderer, preview, theme.dragged_header(&self.style));
+            });
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let Tree {
+            state, children, ..
+        } = tree;
+        let state = state.downcast_mut::<State>();
+
+        if let Some(menu) = self
+            .menu
+            .as_mut()
+            .filter(|_| state.menu.is_some() && children.len() > 1)
+        {
+            return Menu::new(menu, &mut children[1], &mut state.menu).overlay();
+        }
+
+        self.content.as_widget_mut().overlay(
+            &mut children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Header<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn from(header: Header<'a, Message, Renderer>) -> Self {
+        Element::new(header)
+    }
+}
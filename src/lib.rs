@@ -3,44 +3,106 @@
 pub use style::StyleSheet;
 pub use table::{table, Table};
 
+mod body;
 mod divider;
+mod editor;
+mod frame;
+mod header;
+mod menu;
+mod merge;
+mod search;
+mod sizing;
+mod sticky;
 mod style;
+mod toggle;
 
 pub mod table {
     //! Display rows of data into columns
-    use iced_core::{Element, Length, Padding};
-    use iced_widget::{column, container, row, scrollable, Space};
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{Hash, Hasher};
+    use std::rc::Rc;
+    use std::{fmt, io, slice};
 
+    use iced_core::{Alignment, Element, Length, Padding};
+    use iced_widget::{checkbox, column, container, row, scrollable, text, Space};
+
+    use super::body::{self, Body};
     use super::divider::Divider;
-    use super::style;
+    use super::editor::{Draft, Editor};
+    use super::frame::Frame;
+    use super::header::Header;
+    use super::merge::{Heights, Merged};
+    use super::search;
+    use super::sizing::{clamp_width, Cell, Measure, Widths};
+    use super::sticky::{self, Sticky};
+    use super::style::{self, wrapper::Target};
+    use super::toggle::Toggle;
+
+    pub use super::search::match_ranges;
+
+    pub mod export;
 
     /// Creates a new [`Table`] with the provided [`Column`] definitions
     /// and [`Row`](Column::Row) data.
     ///
-    /// `on_sync` is needed to keep the header & footer scrollables in sync with
-    /// the body scrollable. It is up to the consumer to emit a [`scroll_to`](iced_widget::scrollable::scroll_to) operation
-    /// from `update` when this message is received.
+    /// The header & footer are kept horizontally in sync with the body by the [`Table`] itself.
     pub fn table<'a, Column, Row, Message, Renderer>(
-        header: scrollable::Id,
-        body: scrollable::Id,
         columns: &'a [Column],
         rows: &'a [Row],
-        on_sync: fn(scrollable::AbsoluteOffset) -> Message,
     ) -> Table<'a, Column, Row, Message, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
         Renderer::Theme: style::StyleSheet + container::StyleSheet,
     {
         Table {
-            header,
-            body,
-            footer: None,
+            header_id: None,
+            body_id: None,
+            footer_id: None,
+            footer: false,
+            copyable: false,
+            column_groups: &[],
+            filter_row: false,
+            search: "",
+            group_by: None,
+            collapsed_groups: &[],
+            on_group_toggle: None,
+            tree: None,
+            tree_column: 0,
+            on_toggle_expand: None,
             columns,
             rows,
-            on_sync,
+            sorting: &[],
+            on_sort: None,
+            on_multi_sort: None,
+            selection_mode: SelectionMode::None,
+            row_key: None,
+            selected: &[],
+            on_selection_change: None,
+            on_activate: None,
+            on_cell_edit: None,
+            on_paste: None,
+            on_paste_error: None,
+            on_range_select: None,
+            on_column_reorder: None,
+            on_column_autofit: None,
+            column_key: None,
+            managed_resizing: false,
+            on_columns_resized: None,
+            hidden_columns: &[],
+            on_column_visibility: None,
+            on_sync: None,
             on_column_drag: None,
             on_column_release: None,
+            row_heights: None,
+            on_row_drag: None,
+            on_row_release: None,
             min_width: 0.0,
+            revision: 0,
+            row_height: None,
+            overscan: 5,
+            pinned_rows: 0,
             divider_width: 2.0,
             cell_padding: 4.into(),
             style: Default::default(),
@@ -49,17 +111,23 @@ pub mod table {
     }
 
     /// The type used to determine how the width of a [`Column`] should be calculated.
-    #[derive(Debug, Copy, Clone)]
+    #[derive(Debug, Copy, Clone, PartialEq)]
     pub enum Width {
         /// Fixed width; the width cannot be resized.
         Fixed(f32),
-        /// Resizable width, where the current width is the sum of initial and offset.
-        /// The current width can be clamped to a range by the consumer.
+        /// Resizable width, where the current width is the sum of initial and offset,
+        /// clamped between min and max when they're set.
+        ///
+        /// Dragging the divider of the [`Column`] stops at either bound.
         Resizable {
             /// Initial width.
             initial: f32,
             /// Temporary offset when dragged.
             offset: f32,
+            /// Minimum width, if any.
+            min: Option<f32>,
+            /// Maximum width, if any.
+            max: Option<f32>,
         },
         /// Fill the remaining width of the table based on the proportion specified,
         /// shared with all other [`Column`] in the same table.
@@ -69,12 +137,249 @@ pub mod table {
             /// Minimum width (or `0.0f32` to represent no minimum).
             minimum: f32,
         },
+        /// Fit the widest of the header, footer & built cells of the [`Column`], within bounds.
+        ///
+        /// Cells are measured as the table is first laid out, and measured again once the
+        /// rows shown or their order, the hidden columns, the kind of width of any column or the
+        /// [`revision`](Table::revision) changes. Resizing a resizable column doesn't measure
+        /// them again. With a [`virtualized`](Table::virtualized) body, only the rows built
+        /// at the time are measured, until the next measurement.
+        Auto {
+            /// Minimum width.
+            min: f32,
+            /// Maximum width (or `f32::INFINITY` to represent no maximum).
+            max: f32,
+        },
+    }
+
+    /// The direction a [`Column`] is sorted in.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum SortDirection {
+        /// Smallest first, as defined by [`Column::compare`].
+        Ascending,
+        /// Largest first, as defined by [`Column::compare`].
+        Descending,
+        /// The column is not sorted.
+        Unsorted,
+    }
+
+    impl SortDirection {
+        /// The direction a [`Column`] cycles to when its header is clicked;
+        /// ascending, descending, then unsorted.
+        pub fn next(self) -> Self {
+            match self {
+                SortDirection::Unsorted => SortDirection::Ascending,
+                SortDirection::Ascending => SortDirection::Descending,
+                SortDirection::Descending => SortDirection::Unsorted,
+            }
+        }
+    }
+
+    /// A [`Column`] the rows of a [`Table`] are sorted by.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Sort {
+        /// Index of the column.
+        pub column: usize,
+        /// Direction of the sort.
+        pub direction: SortDirection,
+    }
+
+    /// A band heading some of the [`Column`]s of a [`Table`], above their own headers.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ColumnGroup {
+        /// Label of the band.
+        pub label: String,
+        /// Indices of the columns beneath the band.
+        pub columns: Vec<usize>,
+    }
+
+    /// Why a block of cells pasted into a [`Table`] was rejected, given the cells at fault by
+    /// their row & column within the block.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum PasteError {
+        /// The cells fall past the last row or column shown.
+        OutOfBounds(Vec<(usize, usize)>),
+        /// The cells fall in columns which can't be edited, or in group headers or subtotals.
+        NotEditable(Vec<(usize, usize)>),
+    }
+
+    /// A cell of a block pasted into a [`Table`], along with the row & column it lands in.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PastedCell {
+        /// [Path](Table::tree) of the row.
+        pub row: Vec<usize>,
+        /// Index of the column.
+        pub column: usize,
+        /// The pasted value.
+        pub value: String,
+    }
+
+    impl fmt::Display for PasteError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                PasteError::OutOfBounds(cells) => {
+                    write!(f, "{} pasted cells fall outside of the table", cells.len())
+                }
+                PasteError::NotEditable(cells) => {
+                    write!(
+                        f,
+                        "{} pasted cells fall in cells which can't be edited",
+                        cells.len()
+                    )
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for PasteError {}
+
+    /// A rectangular range of cells of a [`Table`], selected by dragging across them.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct RangeSelection {
+        /// [Paths](Table::tree) of the rows within the range, in the order they're shown.
+        ///
+        /// Group headers & subtotals are left out.
+        pub rows: Vec<Vec<usize>>,
+        /// Indices of the columns within the range, in the order they're shown.
+        pub columns: Vec<usize>,
+    }
+
+    /// How rows of a [`Table`] can be selected.
+    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+    pub enum SelectionMode {
+        /// Rows can't be selected.
+        #[default]
+        None,
+        /// A single row can be selected by clicking it, or deselected with a ctrl-click.
+        Single,
+        /// Many rows can be selected; ctrl-click toggles a row and shift-click selects the range
+        /// from the last clicked row.
+        Multi,
+    }
+
+    /// The edge of a [`Table`] a [`Column`] is pinned to.
+    ///
+    /// Pinned columns stay visible while the rest of the columns are scrolled horizontally.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub enum Pin {
+        /// Pinned to the left edge, after any other left pinned columns before it.
+        Left,
+        /// Pinned to the right edge, before any other right pinned columns after it.
+        Right,
+    }
+
+    /// The number of columns & rows covered by a cell of the body, merged into one.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    pub struct Span {
+        /// Number of visible columns, from the column of the cell onwards.
+        pub columns: usize,
+        /// Number of rows, from the row of the cell downwards.
+        pub rows: usize,
+    }
+
+    impl Default for Span {
+        fn default() -> Self {
+            Self {
+                columns: 1,
+                rows: 1,
+            }
+        }
+    }
+
+    // How the dividers of resizable columns resize them
+    #[derive(Clone)]
+    enum Resizing<Message> {
+        // Through messages, applied by the consumer to its columns
+        Messages {
+            on_drag: fn(usize, f32) -> Message,
+            on_release: Message,
+            on_autofit: Option<fn(usize, f32) -> Message>,
+        },
+        // By the table itself, keeping the widths in its state
+        Managed {
+            on_resized: Option<fn(Vec<f32>) -> Message>,
+        },
+    }
+
+    #[derive(Debug, Clone)]
+    struct Pinned {
+        edge: sticky::Edge,
+        before: Vec<usize>, // columns pinned between this one and its edge
+        is_boundary: bool,  // the last column before the scrolling columns
+    }
+
+    // A row of the body
+    #[derive(Debug, Clone, Copy, Hash)]
+    enum Entry {
+        Row(usize),      // index of the row
+        Child(usize),    // index of the child row, beneath its parent
+        Group(usize),    // index of the group, heading its rows
+        Subtotal(usize), // index of the group, beneath its rows
+    }
+
+    // The rows of a table as shown, along with the columns they're shown in
+    struct Shown<'b, Row> {
+        hidden: Vec<bool>,
+        visible: Vec<usize>, // index of each visible column, by its position within a row
+        sorting: Vec<Sort>,  // leaving out the unsorted & unsortable columns
+        order: Vec<usize>,   // rows left by the filters, once sorted
+        groups: Option<Vec<Group>>,
+        entries: Vec<Entry>,
+        pinned_entries: usize, // entries of the pinned rows & their descendants
+        children: Vec<Child<'b, Row>>,
+    }
+
+    // The cells of the body spanning the rows beneath their own
+    #[derive(Debug, Default)]
+    struct RowSpans {
+        rows: HashMap<(usize, usize), usize>, // rows spanned, by position & column in a row
+        covered: HashSet<(usize, usize)>,     // cells left empty beneath them
+    }
+
+    // A descendant of an expanded row
+    struct Child<'a, Row> {
+        row: &'a Row,
+        path: Vec<usize>, // index of the top-level row, then of each row down to this one
+    }
+
+    // The child rows of a tree table, and which rows are expanded
+    struct Tree<'a, Row> {
+        children: fn(&Row) -> &[Row],
+        key: fn(&Row) -> u64,
+        expanded: &'a [u64],
+    }
+
+    impl<'a, Row> Tree<'a, Row> {
+        fn is_expanded(&self, row: &Row) -> bool {
+            self.expanded.contains(&(self.key)(row))
+        }
+    }
+
+    // Not derived, which would require `Row: Copy`
+    impl<'a, Row> Clone for Tree<'a, Row> {
+        fn clone(&self) -> Self {
+            *self
+        }
+    }
+
+    impl<'a, Row> Copy for Tree<'a, Row> {}
+
+    // Indentation of each level of a tree table, also taken by its expanders
+    const TREE_INDENT: f32 = 16.0;
+
+    #[derive(Debug, Clone)]
+    struct Group {
+        key: String,
+        rows: Vec<usize>,
+        is_collapsed: bool,
+        has_subtotal: bool, // any visible column has a group footer
     }
 
-    #[derive(Debug, Clone, Copy)]
-    struct CalculatedWidth {
-        current: f32,
-        is_resizable: bool, // only applicable to resizable widths
+    /// A [`Row`](Column::Row) with rows of its own, shown beneath it once expanded in a
+    /// [tree](Table::tree) [`Table`].
+    pub trait TreeRow: Sized {
+        /// The child rows of this row.
+        fn children(&self) -> &[Self];
     }
 
     /// Defines what a column looks like for each [`Row`](Column::Row) of data.
@@ -85,25 +390,131 @@ pub mod table {
         /// Define the header [`Element`] for this column.
         fn header(&'b self, col_index: usize) -> Element<'a, Message, Renderer>;
 
-        /// Define the cell [`Element`] for this column.
+        /// Define the cell [`Element`] for this column, given the [path](Table::tree) of its row.
         fn cell(
             &'b self,
             col_index: usize,
-            row_index: usize,
+            row_path: &[usize],
             row: &'b Self::Row,
         ) -> Element<'a, Message, Renderer>;
 
-        /// Define the footer [`Element`] for this column.
+        /// Return the [`Span`] of the cell of `row` in this column, laid out across the
+        /// widths of the columns it spans. The cells it covers aren't built.
+        ///
+        /// Only unpinned columns are spanned, and rows are only spanned when the [`Table`]
+        /// isn't [`virtualized`](Table::virtualized). A span stops short of the group headers,
+        /// subtotals and [pinned rows](Table::pinned_rows), as well as any cell spanned from
+        /// the rows above.
+        fn span(&self, _row: &Self::Row) -> Span {
+            Span::default()
+        }
+
+        /// Define the footer [`Element`] for this column, given the rows left by the filters.
         fn footer(
             &'b self,
             _col_index: usize,
-            _rows: &'b [Self::Row],
+            _rows: &[&'b Self::Row],
         ) -> Option<Element<'a, Message, Renderer>> {
             None
         }
 
+        /// Define the subtotal [`Element`] for this column, shown beneath the rows of `group`
+        /// when the [`Table`] is [grouped](Table::group_by).
+        ///
+        /// A subtotal row is only added to the groups once a visible column
+        /// [has a group footer](Column::has_group_footer).
+        fn group_footer(
+            &'b self,
+            _col_index: usize,
+            _group: &str,
+            _rows: &[&'b Self::Row],
+        ) -> Option<Element<'a, Message, Renderer>> {
+            None
+        }
+
+        /// Return `true` if this column defines a [`Column::group_footer`], so each group is
+        /// given a subtotal row without building the footers of the groups out of view.
+        fn has_group_footer(&self) -> bool {
+            false
+        }
+
+        /// Define the filter [`Element`] for this column, shown in the
+        /// [filter row](Table::filter_row).
+        fn filter(&'b self, _col_index: usize) -> Option<Element<'a, Message, Renderer>> {
+            None
+        }
+
+        /// Return `false` to leave `row` out of the [`Table`], e.g. when it doesn't match the
+        /// filter of this column. Only visible columns filter the rows.
+        fn matches_filter(&self, _row: &Self::Row) -> bool {
+            true
+        }
+
+        /// Return the text of the cell of `row` in this column, to be matched by a
+        /// [search](Table::search).
+        fn text(&self, _row: &Self::Row) -> Option<String> {
+            None
+        }
+
+        /// Return the label heading this column in an [export](Table::export).
+        ///
+        /// No header row is exported unless at least one visible column returns a label, the
+        /// others being left empty. As the [`header`](Column::header) of a column is an
+        /// element rather than text, none do by default.
+        fn export_header(&self) -> Option<String> {
+            None
+        }
+
+        /// Return the value of the cell of `row` in this column in an [export](Table::export),
+        /// or when [copied](Table::copyable), e.g. its raw value rather than the formatted text
+        /// of the cell. Defaults to [`Column::text`].
+        fn export_value(&self, row: &Self::Row) -> Option<String> {
+            self.text(row)
+        }
+
+        /// Return `true` if the cells of this column can be edited through
+        /// [`Column::editor`].
+        fn is_editable(&self) -> bool {
+            false
+        }
+
+        /// Return the value the cell of `row` in this column starts with when edited.
+        fn edit_value(&self, row: &Self::Row) -> String {
+            self.text(row).unwrap_or_default()
+        }
+
+        /// Define the editor [`Element`] shown in place of the cell being edited, given its
+        /// current `value`. The editor produces the edited value as its message, e.g. through
+        /// `text_input("", value).on_input(|value| value)`.
+        ///
+        /// The cell itself is kept while this returns `None`.
+        fn editor(
+            &'b self,
+            _col_index: usize,
+            _row_path: &[usize],
+            _row: &'b Self::Row,
+            _value: &str,
+        ) -> Option<Element<'a, String, Renderer>> {
+            None
+        }
+
         /// Return the width type for this column.
         fn width(&self) -> Width;
+
+        /// Return `true` if the rows can be sorted by this column through [`Column::compare`].
+        fn is_sortable(&self) -> bool {
+            false
+        }
+
+        /// Compare two rows when sorting by this column.
+        fn compare(&self, _a: &Self::Row, _b: &Self::Row) -> Ordering {
+            Ordering::Equal
+        }
+
+        /// Return the edge this column is pinned to, if any.
+        fn pin(&self) -> Option<Pin> {
+            None
+        }
     }
 
     /// An element to display rows of data into columns.
@@ -113,15 +524,52 @@ pub mod table {
         Renderer: iced_core::Renderer + 'a,
         Renderer::Theme: style::StyleSheet + container::StyleSheet,
     {
-        header: scrollable::Id,
-        body: scrollable::Id,
-        footer: Option<scrollable::Id>,
+        header_id: Option<scrollable::Id>,
+        body_id: Option<scrollable::Id>,
+        footer_id: Option<scrollable::Id>,
+        footer: bool,
+        copyable: bool,
+        column_groups: &'a [Vec<ColumnGroup>],
+        filter_row: bool,
+        search: &'a str,
+        group_by: Option<fn(&Row) -> String>,
+        collapsed_groups: &'a [String],
+        on_group_toggle: Option<fn(String) -> Message>,
+        tree: Option<Tree<'a, Row>>,
+        tree_column: usize,
+        on_toggle_expand: Option<fn(u64, bool) -> Message>,
         columns: &'a [Column],
         rows: &'a [Row],
-        on_sync: fn(scrollable::AbsoluteOffset) -> Message,
+        sorting: &'a [Sort],
+        on_sort: Option<fn(usize, SortDirection) -> Message>,
+        on_multi_sort: Option<fn(usize, SortDirection) -> Message>,
+        selection_mode: SelectionMode,
+        row_key: Option<fn(&Row) -> u64>,
+        selected: &'a [u64],
+        on_selection_change: Option<fn(Vec<u64>) -> Message>,
+        on_activate: Option<fn(Vec<usize>, usize) -> Message>,
+        on_cell_edit: Option<fn(Vec<usize>, usize, String) -> Message>,
+        on_paste: Option<fn(Vec<usize>, usize, Vec<Vec<PastedCell>>) -> Message>,
+        on_paste_error: Option<fn(PasteError) -> Message>,
+        on_range_select: Option<fn(RangeSelection) -> Message>,
+        on_column_reorder: Option<fn(usize, usize) -> Message>,
+        on_column_autofit: Option<fn(usize, f32) -> Message>,
+        column_key: Option<fn(&Column) -> u64>,
+        managed_resizing: bool,
+        on_columns_resized: Option<fn(Vec<f32>) -> Message>,
+        hidden_columns: &'a [u64],
+        on_column_visibility: Option<fn(usize, bool) -> Message>,
+        on_sync: Option<fn(scrollable::AbsoluteOffset) -> Message>,
         on_column_drag: Option<fn(usize, f32) -> Message>,
         on_column_release: Option<Message>,
+        row_heights: Option<Box<dyn Fn(&[usize], &Row) -> f32 + 'a>>,
+        on_row_drag: Option<fn(Vec<usize>, f32) -> Message>,
+        on_row_release: Option<Message>,
         min_width: f32,
+        revision: u64,
+        row_height: Option<f32>,
+        overscan: usize,
+        pinned_rows: usize,
         divider_width: f32,
         cell_padding: Padding,
         style: <Renderer::Theme as style::StyleSheet>::Style,
@@ -138,7 +586,8 @@ pub mod table {
         /// will enable the resizing interaction.
         ///
         /// `on_drag` will emit a message during an on-going resize. It is up to the consumer to return
-        /// this value for the associated column in [`Column::resize_offset`].
+        /// this value for the associated column as the `offset` of its [`Width::Resizable`].
+        /// The offset is already clamped to the `min` & `max` of that width.
         ///
         /// `on_release` is emited when the resize is finished. It is up to the consumer to apply the last
         /// `on_drag` offset to the column's stored width.
@@ -154,215 +603,1616 @@ pub mod table {
             }
         }
 
-        /// Show the footer returned by [`Column::footer`].
-        pub fn footer(self, footer: scrollable::Id) -> Self {
+        /// Identifies each [`Column`] by the stable key returned by `column_key`, which
+        /// survives the columns being reordered.
+        ///
+        /// The key is shared by [`Table::managed_resizing`] & [`Table::hidden_columns`],
+        /// which both require it.
+        pub fn column_key(self, column_key: fn(&Column) -> u64) -> Self {
             Self {
-                footer: Some(footer),
+                column_key: Some(column_key),
                 ..self
             }
         }
 
-        /// Sets the minimum width of table.
+        /// Lets the [`Table`] resize its [resizable](Width::Resizable) columns on its own,
+        /// keeping their widths in its state by the key set through [`Table::column_key`].
         ///
-        /// This is useful to use in conjuction with [`responsive`](iced_widget::responsive) to ensure
-        /// the table always fills the width of it's parent container.
-        pub fn min_width(self, min_width: f32) -> Self {
-            Self { min_width, ..self }
+        /// Each column starts at its `initial` width, and double-clicking its divider fits
+        /// it to its content as currently built. This takes precedence over
+        /// [`Table::on_column_resize`].
+        pub fn managed_resizing(self) -> Self {
+            Self {
+                managed_resizing: true,
+                ..self
+            }
         }
 
-        /// Sets the width of the column dividers.
-        pub fn divider_width(self, divider_width: f32) -> Self {
+        /// Sets the message that will be produced once a column of a
+        /// [managed](Table::managed_resizing) [`Table`] is resized, with the width of every
+        /// column, e.g. to persist them.
+        pub fn on_columns_resized(self, on_columns_resized: fn(Vec<f32>) -> Message) -> Self {
             Self {
-                divider_width,
+                on_columns_resized: Some(on_columns_resized),
                 ..self
             }
         }
 
-        /// Sets the [`Padding`] used inside each cell of the [`Table`].
-        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
+        /// Hides the columns whose key, as set through [`Table::column_key`], is in
+        /// `hidden_columns`.
+        ///
+        /// Hidden columns aren't built, and take no width from the [`Table`].
+        pub fn hidden_columns(self, hidden_columns: &'a [u64]) -> Self {
             Self {
-                cell_padding: cell_padding.into(),
+                hidden_columns,
                 ..self
             }
         }
 
-        /// Sets the style variant of this [`Table`].
-        pub fn style(
+        /// Sets the message that will be produced when a [`Column`] is shown or hidden from
+        /// the menu opened by right-clicking the header, with the index of the column and
+        /// whether it should be visible.
+        ///
+        /// The menu lists every column by its header, along with a checkbox. It is up to the
+        /// consumer to update the keys passed to [`Table::hidden_columns`].
+        pub fn on_column_visibility(
             self,
-            style: impl Into<<Renderer::Theme as style::StyleSheet>::Style>,
+            on_column_visibility: fn(usize, bool) -> Message,
         ) -> Self {
             Self {
-                style: style.into(),
+                on_column_visibility: Some(on_column_visibility),
                 ..self
             }
         }
 
-        ///  Sets the [`Properties`](iced_widget::scrollable::Properties) used for the table's body scrollable.
-        pub fn scrollable_properties(self, f: impl Fn() -> scrollable::Properties + 'a) -> Self {
+        /// Sorts the rows of the [`Table`] by the given [`Sort`]s, in order of priority,
+        /// through [`Column::compare`].
+        ///
+        /// Sorted columns show an indicator in their header, along with their priority when
+        /// sorting by more than one column.
+        pub fn sorting(self, sorting: &'a [Sort]) -> Self {
+            Self { sorting, ..self }
+        }
+
+        /// Sets the message that will be produced when the header of a
+        /// [sortable](Column::is_sortable) [`Column`] is clicked, with the next [`SortDirection`]
+        /// of that column.
+        ///
+        /// It is up to the consumer to sort by this column alone in [`Table::sorting`].
+        pub fn on_sort(self, on_sort: fn(usize, SortDirection) -> Message) -> Self {
             Self {
-                scrollable_properties: Box::new(f),
+                on_sort: Some(on_sort),
                 ..self
             }
         }
-    }
 
-    impl<'a, 'b, Column, Row, Message, Renderer> From<Table<'b, Column, Row, Message, Renderer>>
-        for Element<'a, Message, Renderer>
-    where
-        Renderer: iced_core::Renderer + 'a,
-        Renderer::Theme: style::StyleSheet + container::StyleSheet + scrollable::StyleSheet,
-        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
-        Message: 'a + Clone,
-    {
-        fn from(table: Table<'b, Column, Row, Message, Renderer>) -> Self {
-            let Table {
-                header,
-                body,
-                footer,
-                columns,
-                rows,
-                on_sync,
-                on_column_drag,
-                on_column_release,
-                min_width,
-                divider_width,
-                cell_padding,
-                style,
-                scrollable_properties,
-            } = table;
+        /// Sets the message that will be produced when the header of a
+        /// [sortable](Column::is_sortable) [`Column`] is shift-clicked, with the next
+        /// [`SortDirection`] of that column.
+        ///
+        /// It is up to the consumer to add, update or remove this column in [`Table::sorting`],
+        /// keeping the other columns it is sorted by. Falls back to [`Table::on_sort`] if unset.
+        pub fn on_multi_sort(self, on_multi_sort: fn(usize, SortDirection) -> Message) -> Self {
+            Self {
+                on_multi_sort: Some(on_multi_sort),
+                ..self
+            }
+        }
 
-            let (calaculated_widths, unused_width) = distribute_fill_widths(columns, min_width);
+        /// Enables row selection with the given [`SelectionMode`].
+        ///
+        /// Rows are identified by the stable key returned by `row_key`, so the selection
+        /// survives the rows being sorted or filtered.
+        pub fn selection(self, selection_mode: SelectionMode, row_key: fn(&Row) -> u64) -> Self {
+            Self {
+                selection_mode,
+                row_key: Some(row_key),
+                ..self
+            }
+        }
 
-            let header = scrollable(style::wrapper::header(
-                row(columns
-                    .iter()
-                    .zip(calaculated_widths.iter())
-                    .enumerate()
-                    .map(|(index, (column, &calculated_width))| {
-                        header_container(
-                            index,
-                            column,
-                            calculated_width,
-                            on_column_drag,
-                            on_column_release.clone(),
-                            divider_width,
-                            cell_padding,
-                            style.clone(),
-                        )
-                    })
-                    .collect()),
-                style.clone(),
-            ))
-            .id(header)
-            .horizontal_scroll(
-                scrollable::Properties::new()
-                    .width(0)
-                    .margin(0)
-                    .scroller_width(0),
-            )
-            .vertical_scroll(
-                scrollable::Properties::new()
-                    .width(0)
-                    .margin(0)
-                    .scroller_width(0),
-            );
+        /// Sets the keys of the currently selected rows, as returned by the `row_key`
+        /// of [`Table::selection`].
+        pub fn selected(self, selected: &'a [u64]) -> Self {
+            Self { selected, ..self }
+        }
 
-            let body = scrollable(column(
-                rows.iter()
-                    .enumerate()
-                    .map(|(row_index, _row)| {
-                        style::wrapper::row(
-                            row(columns
-                                .iter()
-                                .zip(calaculated_widths.iter())
-                                .enumerate()
-                                .map(|(col_index, (column, &calculated_width))| {
-                                    body_container(
-                                        col_index,
-                                        row_index,
-                                        calculated_width,
-                                        column,
-                                        _row,
-                                        divider_width,
-                                        cell_padding,
-                                    )
-                                })
-                                .collect()),
-                            style.clone(),
-                            row_index,
-                        )
-                    })
-                    .collect(),
-            ))
-            .id(body)
-            .on_scroll(move |viewport| {
-                let offset = viewport.absolute_offset();
-                (on_sync)(scrollable::AbsoluteOffset { y: 0.0, ..offset })
-            })
-            .horizontal_scroll((scrollable_properties)())
-            .vertical_scroll((scrollable_properties)())
-            .height(Length::Fill);
+        /// Sets the message that will be produced when the selected rows change, with the keys
+        /// of the new selection. It is up to the consumer to pass them back to [`Table::selected`].
+        pub fn on_selection_change(self, on_selection_change: fn(Vec<u64>) -> Message) -> Self {
+            Self {
+                on_selection_change: Some(on_selection_change),
+                ..self
+            }
+        }
 
-            let footer = footer.map(|footer| {
-                scrollable(style::wrapper::footer(
-                    row(columns
-                        .iter()
-                        .zip(calaculated_widths.iter())
-                        .enumerate()
-                        .map(|(index, (column, &calculated_width))| {
-                            footer_container(
-                                index,
-                                column,
-                                calculated_width,
-                                rows,
-                                on_column_drag,
-                                on_column_release.clone(),
-                                divider_width,
-                                cell_padding,
-                                style.clone(),
-                            )
-                        })
-                        .collect()),
-                    style,
-                ))
-                .id(footer)
-                .horizontal_scroll(
-                    scrollable::Properties::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                )
-                .vertical_scroll(
-                    scrollable::Properties::new()
-                        .width(0)
-                        .margin(0)
-                        .scroller_width(0),
-                )
-            });
+        /// Sets the message that will be produced when Enter is pressed on the focused cell,
+        /// with the [path](Table::tree) of its row and the index of its column. Editable cells
+        /// are edited instead, when [`Table::on_cell_edit`] is set.
+        ///
+        /// A cell is focused by clicking it, and the focus is moved with the arrow keys,
+        /// Home / End (with ctrl to move between the first & last rows), Page Up / Page Down
+        /// and Tab. Once the rows have a key, through [`Table::selection`] or [`Table::tree`],
+        /// the focus follows its row as they're sorted, filtered or grouped.
+        pub fn on_activate(self, on_activate: fn(Vec<usize>, usize) -> Message) -> Self {
+            Self {
+                on_activate: Some(on_activate),
+                ..self
+            }
+        }
 
-            let mut column = column![header, body];
+        /// Sets the message that will be produced when the edit of a cell is committed, with
+        /// the [path](Table::tree) of its row, the index of its column and the edited value.
+        /// Setting this enables editing the cells of [editable](Column::is_editable) columns.
+        ///
+        /// A cell is edited by double-clicking it, or pressing Enter once it's focused, which
+        /// swaps it for its [`Column::editor`]. Enter or clicking elsewhere commits the edit,
+        /// while Escape cancels it. Tab / Shift+Tab commits it to edit the next / previous
+        /// editable cell. Only one cell is edited at a time, and it stays on its row as the
+        /// rows change once they have a key, like the focus.
+        pub fn on_cell_edit(self, on_cell_edit: fn(Vec<usize>, usize, String) -> Message) -> Self {
+            Self {
+                on_cell_edit: Some(on_cell_edit),
+                ..self
+            }
+        }
 
-            if let Some(footer) = footer {
-                column = column.push(footer);
+        /// Sets the message that will be produced when Ctrl+V is pressed on the focused cell,
+        /// with the [path](Table::tree) of its row, the index of its column and the TSV text of
+        /// the clipboard split into a block of [`PastedCell`]s.
+        ///
+        /// The block is laid over the rows & columns as shown, starting from the focused cell,
+        /// so each cell is given the row & column it lands in. The block is only pasted once
+        /// every cell lands in a row of an [editable](Column::is_editable) column, rather than
+        /// past the last row or column, or in a group header or subtotal.
+        pub fn on_paste(
+            self,
+            on_paste: fn(Vec<usize>, usize, Vec<Vec<PastedCell>>) -> Message,
+        ) -> Self {
+            Self {
+                on_paste: Some(on_paste),
+                ..self
             }
+        }
 
-            let mut table_container = container(column).height(Length::Fill).width(Length::Shrink);
+        /// Sets the message that will be produced instead of [`Table::on_paste`] when the
+        /// pasted block is rejected, with the [`PasteError`] at fault.
+        pub fn on_paste_error(self, on_paste_error: fn(PasteError) -> Message) -> Self {
+            Self {
+                on_paste_error: Some(on_paste_error),
+                ..self
+            }
+        }
 
-            if let Some(unused_width) = unused_width {
-                table_container = table_container.padding([0.0, unused_width, 0.0, 0.0]);
+        /// Sets the message that will be produced as a range of cells is selected by pressing on
+        /// a cell and dragging across the others, with the [`RangeSelection`] up to the cell
+        /// under the cursor.
+        ///
+        /// The body scrolls while the cursor is dragged near its edges. The range is drawn
+        /// with the [`StyleSheet::selection`](style::StyleSheet::selection) appearance, and
+        /// copied instead of the selected rows by [`Table::copyable`]. Like the focus, it
+        /// follows its rows as they're sorted, filtered or grouped once they have a key.
+        pub fn on_range_select(self, on_range_select: fn(RangeSelection) -> Message) -> Self {
+            Self {
+                on_range_select: Some(on_range_select),
+                ..self
             }
+        }
 
-            table_container.into()
+        /// Sets the message that will be produced when a header is dragged to reorder its
+        /// [`Column`], with the index it's moved `from` and the index it's moved `to`.
+        ///
+        /// It is up to the consumer to move the column within the `columns` of the [`Table`],
+        /// e.g. by removing it at `from` and inserting it back at `to`.
+        pub fn on_column_reorder(self, on_column_reorder: fn(usize, usize) -> Message) -> Self {
+            Self {
+                on_column_reorder: Some(on_column_reorder),
+                ..self
+            }
         }
-    }
 
-    fn header_container<'a, 'b, Column, Row, Message, Renderer>(
-        index: usize,
-        column: &'b Column,
-        calculated_width: CalculatedWidth,
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
-        divider_width: f32,
+        /// Sets the message that will be produced when the divider of a
+        /// [resizable](Width::Resizable) [`Column`] is double-clicked, with the width fitting
+        /// the widest of its header, footer & built cells.
+        ///
+        /// The cells are measured as they're laid out, so the width fits them as currently
+        /// built, including the rows scrolled into a [`virtualized`](Table::virtualized) body.
+        ///
+        /// It is up to the consumer to apply this width to the column, e.g. as its `initial`
+        /// width with no `offset`. Requires [`Table::on_column_resize`] to be set.
+        pub fn on_column_autofit(self, on_column_autofit: fn(usize, f32) -> Message) -> Self {
+            Self {
+                on_column_autofit: Some(on_column_autofit),
+                ..self
+            }
+        }
+
+        /// Show the footer returned by [`Column::footer`].
+        pub fn footer(self) -> Self {
+            Self {
+                footer: true,
+                ..self
+            }
+        }
+
+        /// Copies the selected rows to the clipboard when Ctrl+C is pressed, or the focused cell
+        /// when none are selected, as TSV through [`Column::export_value`]. A range of cells
+        /// selected through [`Table::on_range_select`] is copied before either.
+        ///
+        /// Hidden columns are left out, and copied cells paste cleanly into spreadsheets.
+        ///
+        /// Only TSV is copied, without an HTML table alongside it, as the
+        /// [`Clipboard`](iced_core::Clipboard) given to widgets only holds plain text.
+        pub fn copyable(self) -> Self {
+            Self {
+                copyable: true,
+                ..self
+            }
+        }
+
+        /// Heads the columns with bands of [`ColumnGroup`]s, in a row above the header for each
+        /// entry of `column_groups`, from the top down.
+        ///
+        /// A band spans the visible columns of its group laid out next to each other, taking
+        /// the sum of their widths as they're resized. Columns outside of any group leave the
+        /// row empty above them, and pinned columns are only spanned along with the columns
+        /// pinned to the same edge.
+        pub fn column_groups(self, column_groups: &'a [Vec<ColumnGroup>]) -> Self {
+            Self {
+                column_groups,
+                ..self
+            }
+        }
+
+        /// Show the filters returned by [`Column::filter`] in a row beneath the header.
+        ///
+        /// The rows are filtered through [`Column::matches_filter`] regardless, apart from
+        /// [pinned rows](Table::pinned_rows) which are always shown.
+        pub fn filter_row(self) -> Self {
+            Self {
+                filter_row: true,
+                ..self
+            }
+        }
+
+        /// Only shows the rows with a cell whose [`Column::text`] contains `query`, ignoring
+        /// case. Nothing is left out while `query` is empty, and
+        /// [pinned rows](Table::pinned_rows) are always shown.
+        ///
+        /// Pressing F3 / Shift+F3 focuses the next / previous matching cell, scrolling the body
+        /// to it. Cells can highlight the matched text through [`match_ranges`].
+        pub fn search(self, query: &'a str) -> Self {
+            Self {
+                search: query,
+                ..self
+            }
+        }
+
+        /// Groups the rows of the [`Table`] by the key returned by `group_by`, heading each
+        /// group with a row showing its key and number of rows.
+        ///
+        /// Groups are ordered by their first row, once sorted, while
+        /// [pinned rows](Table::pinned_rows) stay ungrouped above them. Group headers are
+        /// styled by [`StyleSheet::group_header`](crate::StyleSheet::group_header).
+        pub fn group_by(self, group_by: fn(&Row) -> String) -> Self {
+            Self {
+                group_by: Some(group_by),
+                ..self
+            }
+        }
+
+        /// Sets the keys of the collapsed groups, whose rows are left out of the [`Table`]
+        /// along with their subtotals.
+        pub fn collapsed_groups(self, collapsed_groups: &'a [String]) -> Self {
+            Self {
+                collapsed_groups,
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the header of a group is clicked, with
+        /// the key of the group.
+        ///
+        /// It is up to the consumer to add or remove the key in [`Table::collapsed_groups`].
+        pub fn on_group_toggle(self, on_group_toggle: fn(String) -> Message) -> Self {
+            Self {
+                on_group_toggle: Some(on_group_toggle),
+                ..self
+            }
+        }
+
+        /// Shows the [children](TreeRow::children) of each expanded row beneath it, indented
+        /// in the [`tree_column`](Table::tree_column) after an expander toggling the row.
+        ///
+        /// Rows are expanded while their key, as returned by `row_key`, is in `expanded`.
+        /// Children are sorted like the top-level rows, while filters & searches only apply to
+        /// the top-level rows.
+        ///
+        /// Rows are addressed by their path: the index of their top-level row, followed by the
+        /// index of each row down to them among the children of its parent. The path of a
+        /// top-level row, as of any row of a table which isn't a tree, is its index alone.
+        pub fn tree(self, expanded: &'a [u64], row_key: fn(&Row) -> u64) -> Self
+        where
+            Row: TreeRow,
+        {
+            Self {
+                tree: Some(Tree {
+                    children: Row::children,
+                    key: row_key,
+                    expanded,
+                }),
+                ..self
+            }
+        }
+
+        /// Sets the index of the [`Column`] showing the indentation & expanders of a
+        /// [tree](Table::tree). Defaults to the first column.
+        pub fn tree_column(self, tree_column: usize) -> Self {
+            Self {
+                tree_column,
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a row of a [tree](Table::tree) is
+        /// expanded or collapsed, with its key and whether it should be expanded.
+        ///
+        /// Rows are toggled by clicking their expander, or by pressing Right / Left once one of
+        /// their cells is focused. It is up to the consumer to update the keys passed to
+        /// [`Table::tree`].
+        pub fn on_toggle_expand(self, on_toggle_expand: fn(u64, bool) -> Message) -> Self {
+            Self {
+                on_toggle_expand: Some(on_toggle_expand),
+                ..self
+            }
+        }
+
+        /// Sets the [`Id`](scrollable::Id) of the header scrollable.
+        pub fn header_id(self, id: scrollable::Id) -> Self {
+            Self {
+                header_id: Some(id),
+                ..self
+            }
+        }
+
+        /// Sets the [`Id`](scrollable::Id) of the body scrollable.
+        ///
+        /// This can be used to [`scroll_to`](iced_widget::scrollable::scroll_to) the body, which
+        /// the header & footer will follow.
+        pub fn body_id(self, id: scrollable::Id) -> Self {
+            Self {
+                body_id: Some(id),
+                ..self
+            }
+        }
+
+        /// Sets the [`Id`](scrollable::Id) of the footer scrollable.
+        pub fn footer_id(self, id: scrollable::Id) -> Self {
+            Self {
+                footer_id: Some(id),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when the body is scrolled, with the
+        /// horizontal offset shared by the header & footer.
+        ///
+        /// The [`Table`] keeps them in sync on its own; this is only needed to observe the offset.
+        pub fn on_sync(self, on_sync: fn(scrollable::AbsoluteOffset) -> Message) -> Self {
+            Self {
+                on_sync: Some(on_sync),
+                ..self
+            }
+        }
+
+        /// Sets the minimum width of table.
+        ///
+        /// This is useful to use in conjuction with [`responsive`](iced_widget::responsive) to ensure
+        /// the table always fills the width of it's parent container.
+        pub fn min_width(self, min_width: f32) -> Self {
+            Self { min_width, ..self }
+        }
+
+        /// Sets the revision of the content of the [`Table`], which the consumer changes
+        /// whenever the content of its cells does without the rows shown changing, e.g. once
+        /// a cell is edited or the rows are replaced by as many.
+        ///
+        /// [`Width::Auto`] columns are measured again once the revision changes.
+        pub fn revision(self, revision: u64) -> Self {
+            Self { revision, ..self }
+        }
+
+        /// Virtualizes the body of the [`Table`], giving every row a fixed `row_height` unless
+        /// set through [`Table::row_heights`].
+        ///
+        /// Only the rows intersecting the body's viewport, plus the [`overscan`](Self::overscan),
+        /// will have their cells built through [`Column::cell`]. This keeps large tables cheap to
+        /// `view`, as the remaining rows are replaced by spacers of the same height.
+        pub fn virtualized(self, row_height: f32) -> Self {
+            Self {
+                row_height: Some(row_height),
+                ..self
+            }
+        }
+
+        /// Gives each row the height returned by `row_heights`, given its [path](Table::tree) &
+        /// data, rather than the height of its tallest cell.
+        ///
+        /// This lets a [`virtualized`](Self::virtualized) [`Table`] know the height of every
+        /// row up front, leaving its fixed `row_height` to the group headers & subtotals.
+        pub fn row_heights(self, row_heights: impl Fn(&[usize], &Row) -> f32 + 'a) -> Self {
+            Self {
+                row_heights: Some(Box::new(row_heights)),
+                ..self
+            }
+        }
+
+        /// Sets the message that will be produced when a row is resizing, by dragging the
+        /// divider on its bottom edge. Setting this will enable the resizing interaction for
+        /// the rows, once their heights are set through [`Table::row_heights`].
+        ///
+        /// `on_drag` will emit a message with the [path](Table::tree) of the row during an
+        /// on-going resize.
+        /// It is up to the consumer to add this offset to the height returned for the row. The
+        /// offset is already clamped so the row can't be shrunk past nothing.
+        ///
+        /// `on_release` is emitted when the resize is finished. It is up to the consumer to apply
+        /// the last `on_drag` offset to the row's stored height.
+        pub fn on_row_resize(
+            self,
+            on_drag: fn(Vec<usize>, f32) -> Message,
+            on_release: Message,
+        ) -> Self {
+            Self {
+                on_row_drag: Some(on_drag),
+                on_row_release: Some(on_release),
+                ..self
+            }
+        }
+
+        /// Sets the number of rows built either side of the viewport of a
+        /// [`virtualized`](Self::virtualized) [`Table`]. Defaults to `5`.
+        pub fn overscan(self, overscan: usize) -> Self {
+            Self { overscan, ..self }
+        }
+
+        /// Pins the first `pinned_rows` rows to the top of the body, where they stay visible
+        /// while the other rows are scrolled vertically.
+        ///
+        /// Pinned rows keep their order when the [`Table`] is sorted, and are styled by
+        /// [`StyleSheet::pinned_row`](crate::StyleSheet::pinned_row).
+        pub fn pinned_rows(self, pinned_rows: usize) -> Self {
+            Self {
+                pinned_rows,
+                ..self
+            }
+        }
+
+        /// Sets the width of the column dividers.
+        pub fn divider_width(self, divider_width: f32) -> Self {
+            Self {
+                divider_width,
+                ..self
+            }
+        }
+
+        /// Sets the [`Padding`] used inside each cell of the [`Table`].
+        pub fn cell_padding(self, cell_padding: impl Into<Padding>) -> Self {
+            Self {
+                cell_padding: cell_padding.into(),
+                ..self
+            }
+        }
+
+        /// Sets the style variant of this [`Table`].
+        pub fn style(
+            self,
+            style: impl Into<<Renderer::Theme as style::StyleSheet>::Style>,
+        ) -> Self {
+            Self {
+                style: style.into(),
+                ..self
+            }
+        }
+
+        ///  Sets the [`Properties`](iced_widget::scrollable::Properties) used for the table's body scrollable.
+        pub fn scrollable_properties(self, f: impl Fn() -> scrollable::Properties + 'a) -> Self {
+            Self {
+                scrollable_properties: Box::new(f),
+                ..self
+            }
+        }
+    }
+
+    impl<'a, 'b, Column, Row, Message, Renderer> Table<'b, Column, Row, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'b,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+        'b: 'a,
+    {
+        /// Writes the rows shown by the [`Table`] to `writer` in the given
+        /// [`Format`](export::Format), through [`Column::export_value`].
+        ///
+        /// The rows are filtered, sorted, grouped & expanded as they're shown, leaving out the
+        /// group headers & subtotals. Hidden columns are left out, and the others are written in
+        /// order.
+        ///
+        /// The rows are headed by a row of the [`Column::export_header`] of each column, which
+        /// is only written when at least one visible column returns a label. Without any, as by
+        /// default, the export starts with the first row.
+        pub fn export(&self, mut writer: impl io::Write, format: export::Format) -> io::Result<()> {
+            let columns = self.columns;
+            let rows = self.rows;
+
+            let Shown {
+                visible,
+                entries,
+                children,
+                ..
+            } = self.shown();
+
+            let headers: Vec<Option<String>> = visible
+                .iter()
+                .map(|&index| columns[index].export_header())
+                .collect();
+
+            if headers.iter().any(Option::is_some) {
+                export::write_record(
+                    &mut writer,
+                    format,
+                    headers.into_iter().map(Option::unwrap_or_default),
+                )?;
+            }
+
+            for entry in &entries {
+                let Some((_, row)) = entry_row(entry, rows, &children) else {
+                    continue;
+                };
+
+                export::write_record(
+                    &mut writer,
+                    format,
+                    visible
+                        .iter()
+                        .map(|&index| columns[index].export_value(row).unwrap_or_default()),
+                )?;
+            }
+
+            writer.flush()
+        }
+
+        // The rows as shown by the body, filtered, sorted, grouped & expanded, along with the
+        // columns they're shown in. Exports go through it too, so they write the rows as shown.
+        fn shown(&self) -> Shown<'b, Row> {
+            let columns = self.columns;
+            let rows = self.rows;
+            let pinned_rows = self.pinned_rows.min(rows.len());
+
+            let hidden = hidden_mask(columns, self.hidden_columns, self.column_key);
+            let visible: Vec<usize> = (0..columns.len()).filter(|&index| !hidden[index]).collect();
+            let sorting = applied_sorting(columns, self.sorting);
+
+            let order = sort_order(
+                columns,
+                rows,
+                filter_rows(columns, rows, &visible, self.search, pinned_rows),
+                &sorting,
+                pinned_rows,
+            );
+
+            let groups = self.group_by.map(|group_by| {
+                group_rows(
+                    columns,
+                    rows,
+                    &visible,
+                    &order[pinned_rows..],
+                    group_by,
+                    self.collapsed_groups,
+                )
+            });
+
+            let entries = body_entries(&order, pinned_rows, groups.as_deref());
+
+            let mut children = vec![];
+            let (entries, pinned_entries) = match self.tree {
+                Some(tree) => expand_rows(
+                    columns,
+                    rows,
+                    entries,
+                    pinned_rows,
+                    &sorting,
+                    tree,
+                    &mut children,
+                ),
+                None => (entries, pinned_rows),
+            };
+
+            Shown {
+                hidden,
+                visible,
+                sorting,
+                order,
+                groups,
+                entries,
+                pinned_entries,
+                children,
+            }
+        }
+    }
+
+    impl<'a, 'b, Column, Row, Message, Renderer> From<Table<'b, Column, Row, Message, Renderer>>
+        for Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + iced_core::text::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet
+            + container::StyleSheet
+            + scrollable::StyleSheet
+            + text::StyleSheet
+            + checkbox::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+        'b: 'a,
+    {
+        fn from(table: Table<'b, Column, Row, Message, Renderer>) -> Self {
+            let Shown {
+                hidden,
+                visible,
+                sorting,
+                order,
+                groups,
+                entries,
+                pinned_entries,
+                children,
+            } = table.shown();
+            let children = Rc::new(children);
+
+            let Table {
+                header_id,
+                body_id,
+                footer_id,
+                footer,
+                copyable,
+                column_groups,
+                filter_row,
+                search,
+                group_by: _,
+                collapsed_groups: _,
+                on_group_toggle,
+                tree,
+                tree_column,
+                on_toggle_expand,
+                columns,
+                rows,
+                sorting: _,
+                on_sort,
+                on_multi_sort,
+                selection_mode,
+                row_key,
+                selected,
+                on_selection_change,
+                on_activate,
+                on_cell_edit,
+                on_paste,
+                on_paste_error,
+                on_range_select,
+                on_column_reorder,
+                on_column_autofit,
+                column_key,
+                managed_resizing,
+                on_columns_resized,
+                hidden_columns: _,
+                on_column_visibility,
+                on_sync,
+                on_column_drag,
+                on_column_release,
+                row_heights,
+                on_row_drag,
+                on_row_release,
+                min_width,
+                revision,
+                row_height,
+                overscan,
+                pinned_rows: _,
+                divider_width,
+                cell_padding,
+                style,
+                scrollable_properties,
+            } = table;
+
+            let managed_key = column_key.filter(|_| managed_resizing);
+
+            let resizing = match managed_key {
+                Some(_) => Some(Resizing::Managed {
+                    on_resized: on_columns_resized,
+                }),
+                None => on_column_drag
+                    .zip(on_column_release)
+                    .map(|(on_drag, on_release)| Resizing::Messages {
+                        on_drag,
+                        on_release,
+                        on_autofit: on_column_autofit,
+                    }),
+            };
+
+            // Known up front when virtualized, where the fixed row height is left to the rows
+            // without a height of their own
+            let entry_heights: Vec<Option<f32>> = entries
+                .iter()
+                .map(
+                    |entry| match (&row_heights, entry_row(entry, rows, &children)) {
+                        (Some(row_heights), Some((path, row))) => Some((row_heights)(path, row)),
+                        _ => row_height,
+                    },
+                )
+                .collect();
+
+            let row_resizing = on_row_drag
+                .zip(on_row_release)
+                .filter(|_| row_heights.is_some());
+
+            // The rows shown, in order, so filtering, sorting or grouping them measures again
+            let shown = {
+                let mut hasher = DefaultHasher::new();
+
+                entries.hash(&mut hasher);
+                hasher.finish()
+            };
+
+            let widths = Widths::new(
+                columns.iter().map(|column| column.width()).collect(),
+                managed_key.map_or_else(Vec::new, |key| columns.iter().map(key).collect()),
+                hidden.clone(),
+                min_width,
+                (shown, revision),
+                matches!(
+                    resizing,
+                    Some(
+                        Resizing::Managed { .. }
+                            | Resizing::Messages {
+                                on_autofit: Some(_),
+                                ..
+                            }
+                    )
+                ),
+            );
+
+            let pinned = pinned_columns(columns, &hidden);
+
+            // Every row is built unless virtualized, so only then can cells span rows
+            let row_spans = match row_height {
+                Some(_) => RowSpans::default(),
+                None => row_spans(
+                    columns,
+                    rows,
+                    &entries,
+                    &children,
+                    &visible,
+                    &pinned,
+                    pinned_entries,
+                ),
+            };
+            let heights = Heights::default();
+
+            let mut header =
+                Header::new(
+                    row(visible
+                        .iter()
+                        .map(|&index| {
+                            let column = &columns[index];
+                            let sort = sorting.iter().position(|sort| sort.column == index).map(
+                                |priority| {
+                                    (
+                                        sorting[priority].direction,
+                                        (sorting.len() > 1).then_some(priority + 1),
+                                    )
+                                },
+                            );
+
+                            pin(
+                                header_container(
+                                    index,
+                                    column,
+                                    widths.clone(),
+                                    sort,
+                                    resizing.clone(),
+                                    divider_width,
+                                    cell_padding,
+                                    style.clone(),
+                                ),
+                                pinned[index].as_ref(),
+                                &widths,
+                                Target::Header,
+                                divider_width,
+                                style.clone(),
+                            )
+                        })
+                        .collect()),
+                    style.clone(),
+                    {
+                        let sorting = sorting.clone();
+                        let visible = visible.clone();
+
+                        move |position, modifiers| {
+                            let index = *visible.get(position)?;
+
+                            if !columns.get(index)?.is_sortable() {
+                                return None;
+                            }
+
+                            let direction = sorting
+                                .iter()
+                                .find(|sort| sort.column == index)
+                                .map_or(SortDirection::Unsorted, |sort| sort.direction)
+                                .next();
+
+                            let on_sort = if modifiers.shift() {
+                                on_multi_sort.or(on_sort)
+                            } else {
+                                on_sort
+                            };
+
+                            on_sort.map(|on_sort| (on_sort)(index, direction))
+                        }
+                    },
+                );
+
+            if let Some(on_column_reorder) = on_column_reorder {
+                let visible = visible.clone();
+
+                header = header.on_reorder(
+                    move |from, to| (on_column_reorder)(visible[from], visible[to]),
+                    divider_width,
+                );
+            }
+
+            if let Some(on_column_visibility) = on_column_visibility {
+                header = header.menu(style::wrapper::column_menu(
+                    container(column(
+                        columns
+                            .iter()
+                            .enumerate()
+                            .map(|(index, column)| {
+                                row![
+                                    checkbox("", !hidden[index], move |is_visible| {
+                                        (on_column_visibility)(index, is_visible)
+                                    }),
+                                    column.header(index)
+                                ]
+                                .spacing(4)
+                                .align_items(Alignment::Center)
+                                .into()
+                            })
+                            .collect(),
+                    ))
+                    .padding(cell_padding),
+                    style.clone(),
+                ));
+            }
+
+            let mut header = column(
+                column_groups
+                    .iter()
+                    .map(|groups| {
+                        header_band(
+                            groups,
+                            &visible,
+                            &pinned,
+                            &widths,
+                            divider_width,
+                            cell_padding,
+                            style.clone(),
+                        )
+                    })
+                    .chain(std::iter::once(style::wrapper::header(
+                        header,
+                        style.clone(),
+                    )))
+                    .collect(),
+            );
+
+            // In the header scrollable, to stay in sync with it
+            if filter_row {
+                header = header.push(style::wrapper::header(
+                    row(visible
+                        .iter()
+                        .map(|&index| {
+                            pin(
+                                filter_container(
+                                    index,
+                                    &columns[index],
+                                    widths.clone(),
+                                    divider_width,
+                                    cell_padding,
+                                ),
+                                pinned[index].as_ref(),
+                                &widths,
+                                Target::Header,
+                                divider_width,
+                                style.clone(),
+                            )
+                        })
+                        .collect()),
+                    style.clone(),
+                ));
+            }
+
+            let header = scrollable(header)
+                .horizontal_scroll(
+                    scrollable::Properties::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                )
+                .vertical_scroll(
+                    scrollable::Properties::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                );
+
+            let selected_keys: HashSet<u64> = selected.iter().copied().collect();
+
+            let mut body = Body::new(
+                entries.len(),
+                visible.len(),
+                row_height.map(|_| body::RowHeights::new(entry_heights.iter().flatten().copied())),
+                overscan,
+                style.clone(),
+                {
+                    let widths = widths.clone();
+                    let pinned = pinned.clone();
+                    let style = style.clone();
+                    let entries = entries.clone();
+                    let children = children.clone();
+                    let groups = groups.unwrap_or_default();
+                    let visible = visible.clone();
+                    let heights = heights.clone();
+
+                    move |position, editing: Option<(usize, Draft)>| {
+                        let row_height = entry_heights[position];
+                        let (row_path, row_data) = match &entries[position] {
+                            Entry::Row(index) => (slice::from_ref(index), &rows[*index]),
+                            Entry::Child(index) => {
+                                let child = &children[*index];
+
+                                (child.path.as_slice(), child.row)
+                            }
+                            Entry::Group(index) => {
+                                return group_header(
+                                    &groups[*index],
+                                    &visible,
+                                    widths.clone(),
+                                    row_height,
+                                    cell_padding,
+                                    on_group_toggle,
+                                    style.clone(),
+                                );
+                            }
+                            Entry::Subtotal(index) => {
+                                let group = &groups[*index];
+                                let group_rows: Vec<&Row> =
+                                    group.rows.iter().map(|&index| &rows[index]).collect();
+
+                                let cells = row(visible
+                                    .iter()
+                                    .map(|&col_index| {
+                                        pin(
+                                            subtotal_container(
+                                                col_index,
+                                                &columns[col_index],
+                                                widths.clone(),
+                                                &group.key,
+                                                &group_rows,
+                                                divider_width,
+                                                cell_padding,
+                                            ),
+                                            pinned[col_index].as_ref(),
+                                            &widths,
+                                            Target::Footer,
+                                            divider_width,
+                                            style.clone(),
+                                        )
+                                    })
+                                    .collect());
+
+                                let cells = if let Some(row_height) = row_height {
+                                    cells.height(row_height)
+                                } else {
+                                    cells
+                                };
+
+                                return style::wrapper::footer(cells, style.clone());
+                            }
+                        };
+                        let is_selected = row_key.map_or(false, |row_key| {
+                            selected_keys.contains(&(row_key)(row_data))
+                        });
+                        let is_pinned = position < pinned_entries;
+                        let target = if is_selected {
+                            Target::SelectedRow { index: position }
+                        } else if is_pinned {
+                            Target::PinnedRow { index: position }
+                        } else {
+                            Target::Row { index: position }
+                        };
+
+                        // Columns covered by the last cell spanning several of them
+                        let mut spanned = 0..0;
+
+                        let cells = row(visible
+                            .iter()
+                            .enumerate()
+                            .map(|(column, &col_index)| {
+                                // Covered cells are left empty, so the others keep their
+                                // position within the row
+                                if spanned.contains(&column) {
+                                    let empty = Space::with_width(Length::Fill);
+
+                                    return Cell::spanning(empty, vec![], widths.clone()).into();
+                                }
+
+                                if row_spans.covered.contains(&(position, column)) {
+                                    let empty = Space::with_width(Length::Fill);
+
+                                    return Cell::new(empty, col_index, widths.clone()).into();
+                                }
+
+                                let span = spanned_columns(
+                                    columns[col_index].span(row_data),
+                                    position,
+                                    column,
+                                    &visible,
+                                    &pinned,
+                                    &row_spans.covered,
+                                );
+                                spanned = column + 1..column + span;
+
+                                let draft = editing
+                                    .as_ref()
+                                    .filter(|(editing, _)| *editing == column)
+                                    .map(|(_, draft)| draft.clone());
+
+                                let cell = body_container(
+                                    col_index,
+                                    visible[column..column + span].to_vec(),
+                                    row_path,
+                                    widths.clone(),
+                                    &columns[col_index],
+                                    row_data,
+                                    tree.filter(|_| col_index == tree_column).map(|tree| {
+                                        let depth = row_path.len() - 1;
+
+                                        expander(tree, row_data, depth, on_toggle_expand)
+                                    }),
+                                    draft,
+                                    divider_width,
+                                    cell_padding,
+                                );
+
+                                let cell = match row_spans.rows.get(&(position, column)) {
+                                    Some(&rows) => Merged::new(
+                                        cell,
+                                        position..position + rows,
+                                        pinned_entries,
+                                        heights.clone(),
+                                        target,
+                                        style.clone(),
+                                    )
+                                    .into(),
+                                    None => cell,
+                                };
+
+                                pin(
+                                    cell,
+                                    pinned[col_index].as_ref(),
+                                    &widths,
+                                    target,
+                                    divider_width,
+                                    style.clone(),
+                                )
+                            })
+                            .collect());
+
+                        let cells = if let Some(row_height) = row_height {
+                            cells.height(row_height)
+                        } else {
+                            cells
+                        };
+
+                        let cells = match (&row_resizing, row_height) {
+                            (Some((on_drag, on_release)), Some(height)) => {
+                                let on_drag = *on_drag;
+                                let on_release = on_release.clone();
+                                let row_path = row_path.to_vec();
+
+                                Divider::new(
+                                    cells,
+                                    divider_width,
+                                    move |offset| Some((on_drag)(row_path.clone(), offset)),
+                                    move || Some(on_release.clone()),
+                                    style.clone(),
+                                )
+                                .bottom()
+                                .range(-height, f32::INFINITY)
+                                .into()
+                            }
+                            _ => Element::from(cells),
+                        };
+
+                        if is_selected {
+                            style::wrapper::selected_row(cells, style.clone(), position)
+                        } else if is_pinned {
+                            style::wrapper::pinned_row(cells, style.clone(), position)
+                        } else {
+                            style::wrapper::row(cells, style.clone(), position)
+                        }
+                    }
+                },
+            );
+
+            body = body.pinned(pinned_entries, divider_width).heights(heights);
+
+            if !search.is_empty() {
+                body = body.matches(
+                    entries
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(position, entry)| {
+                            Some((position, entry_row(entry, rows, &children)?.1))
+                        })
+                        .flat_map(|(position, row)| {
+                            visible
+                                .iter()
+                                .enumerate()
+                                .filter(move |(_, &index)| {
+                                    columns[index]
+                                        .text(row)
+                                        .map_or(false, |text| search::is_match(&text, search))
+                                })
+                                .map(move |(column, _)| (position, column))
+                        })
+                        .collect(),
+                );
+            }
+
+            if let Some(on_activate) = on_activate {
+                let entries = entries.clone();
+                let children = children.clone();
+                let visible = visible.clone();
+
+                body = body.on_activate(move |position, column| {
+                    let (row_path, _) = entry_row(&entries[position], rows, &children)?;
+
+                    Some((on_activate)(row_path.to_vec(), visible[column]))
+                });
+            }
+
+            if let Some(on_cell_edit) = on_cell_edit {
+                let value = {
+                    let entries = entries.clone();
+                    let children = children.clone();
+                    let visible = visible.clone();
+
+                    move |position: usize, column: usize| {
+                        let (_, row) = entry_row(&entries[position], rows, &children)?;
+                        let column = &columns[visible[column]];
+
+                        column.is_editable().then(|| column.edit_value(row))
+                    }
+                };
+
+                let on_commit = {
+                    let entries = entries.clone();
+                    let children = children.clone();
+                    let visible = visible.clone();
+
+                    move |position: usize, column: usize, value| {
+                        let (row_path, _) = entry_row(&entries[position], rows, &children)?;
+
+                        Some((on_cell_edit)(row_path.to_vec(), visible[column], value))
+                    }
+                };
+
+                body = body.edit(body::Edit {
+                    value: Box::new(value),
+                    on_commit: Box::new(on_commit),
+                });
+            }
+
+            if let Some(on_paste) = on_paste {
+                let entries = entries.clone();
+                let children = children.clone();
+                let visible = visible.clone();
+
+                body = body.paste(move |position, column, text| {
+                    let values = export::read_records(&text, export::Format::Tsv);
+
+                    // The entry & column index each cell of the block lands in, as shown
+                    let target = |i: usize, j: usize| {
+                        let entry = entries.get(position + i)?;
+                        let index = *visible.get(column + j)?;
+
+                        Some((entry, index))
+                    };
+
+                    let cells = values
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, line)| (0..line.len()).map(move |j| (i, j)));
+                    let (out_of_bounds, cells): (Vec<_>, Vec<_>) =
+                        cells.partition(|&(i, j)| target(i, j).is_none());
+                    let not_editable: Vec<_> = cells
+                        .into_iter()
+                        .filter(|&(i, j)| {
+                            target(i, j).map_or(true, |(entry, column)| {
+                                entry_row(entry, rows, &children).is_none()
+                                    || !columns[column].is_editable()
+                            })
+                        })
+                        .collect();
+
+                    let error = if !out_of_bounds.is_empty() {
+                        PasteError::OutOfBounds(out_of_bounds)
+                    } else if !not_editable.is_empty() {
+                        PasteError::NotEditable(not_editable)
+                    } else {
+                        let block: Vec<Vec<PastedCell>> = values
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, line)| {
+                                line.into_iter()
+                                    .enumerate()
+                                    .filter_map(|(j, value)| {
+                                        let (entry, column) = target(i, j)?;
+                                        let (row_path, _) = entry_row(entry, rows, &children)?;
+
+                                        Some(PastedCell {
+                                            row: row_path.to_vec(),
+                                            column,
+                                            value,
+                                        })
+                                    })
+                                    .collect()
+                            })
+                            .collect();
+
+                        let (row, column) = block
+                            .first()
+                            .and_then(|line| line.first())
+                            .map(|cell| (cell.row.clone(), cell.column))?;
+
+                        return Some((on_paste)(row, column, block));
+                    };
+
+                    on_paste_error.map(|on_paste_error| (on_paste_error)(error))
+                });
+            }
+
+            if let Some((tree, on_toggle_expand)) = tree.zip(on_toggle_expand) {
+                let entries = entries.clone();
+                let children = children.clone();
+
+                body = body.on_expand(move |position, expand| {
+                    let (_, row) = entry_row(&entries[position], rows, &children)?;
+                    let is_toggled =
+                        !(tree.children)(row).is_empty() && tree.is_expanded(row) != expand;
+
+                    is_toggled.then(|| (on_toggle_expand)((tree.key)(row), expand))
+                });
+            }
+
+            if let Some(on_range_select) = on_range_select {
+                let entries = entries.clone();
+                let children = children.clone();
+                let visible = visible.clone();
+
+                body = body.on_range_select(move |positions, range| {
+                    let paths = entries[positions]
+                        .iter()
+                        .filter_map(|entry| Some(entry_row(entry, rows, &children)?.0.to_vec()))
+                        .collect();
+
+                    Some((on_range_select)(RangeSelection {
+                        rows: paths,
+                        columns: visible[range].to_vec(),
+                    }))
+                });
+            }
+
+            if copyable {
+                let entries = entries.clone();
+                let children = children.clone();
+                let visible = visible.clone();
+
+                body = body.copy(move |positions, range| {
+                    let mut contents = vec![];
+
+                    for &position in positions {
+                        let Some((_, row)) = entry_row(&entries[position], rows, &children) else {
+                            continue;
+                        };
+
+                        // Writing to a `Vec` can't fail
+                        let _ = export::write_record(
+                            &mut contents,
+                            export::Format::Tsv,
+                            visible[range.clone()]
+                                .iter()
+                                .map(|&index| columns[index].export_value(row).unwrap_or_default()),
+                        );
+                    }
+
+                    String::from_utf8(contents).unwrap_or_default()
+                });
+            }
+
+            // Also keeps the focus on its row as the rows change
+            let row_key = row_key.or(tree.map(|tree| tree.key));
+
+            if let Some(row_key) = row_key {
+                body = body.row_key(move |position| {
+                    let (_, row) = entry_row(&entries[position], rows, &children)?;
+
+                    Some((row_key)(row))
+                });
+            }
+
+            if let Some((_, on_change)) = row_key.zip(on_selection_change) {
+                if selection_mode != SelectionMode::None {
+                    body = body.selection(body::Selection {
+                        mode: selection_mode,
+                        selected,
+                        on_change,
+                    });
+                }
+            }
+
+            let body = scrollable(body)
+                .horizontal_scroll((scrollable_properties)())
+                .vertical_scroll((scrollable_properties)())
+                .height(Length::Fill);
+
+            let header = match header_id {
+                Some(id) => header.id(id),
+                None => header,
+            };
+
+            let body = match body_id {
+                Some(id) => body.id(id),
+                None => body,
+            };
+
+            let body = match on_sync {
+                Some(on_sync) => body.on_scroll(move |viewport| {
+                    let offset = viewport.absolute_offset();
+                    (on_sync)(scrollable::AbsoluteOffset { y: 0.0, ..offset })
+                }),
+                None => body,
+            };
+
+            let footer = footer.then(|| {
+                let rows: Vec<&Row> = order.iter().map(|&index| &rows[index]).collect();
+
+                let footer = scrollable(style::wrapper::footer(
+                    row(visible
+                        .iter()
+                        .map(|&index| {
+                            pin(
+                                footer_container(
+                                    index,
+                                    &columns[index],
+                                    widths.clone(),
+                                    &rows,
+                                    resizing.clone(),
+                                    divider_width,
+                                    cell_padding,
+                                    style.clone(),
+                                ),
+                                pinned[index].as_ref(),
+                                &widths,
+                                Target::Footer,
+                                divider_width,
+                                style.clone(),
+                            )
+                        })
+                        .collect()),
+                    style,
+                ))
+                .horizontal_scroll(
+                    scrollable::Properties::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                )
+                .vertical_scroll(
+                    scrollable::Properties::new()
+                        .width(0)
+                        .margin(0)
+                        .scroller_width(0),
+                );
+
+                match footer_id {
+                    Some(id) => footer.id(id),
+                    None => footer,
+                }
+            });
+
+            let mut column = column![header, body];
+
+            if let Some(footer) = footer {
+                column = column.push(footer);
+            }
+
+            container(Frame::new(column, widths))
+                .height(Length::Fill)
+                .width(Length::Shrink)
+                .into()
+        }
+    }
+
+    // Whether each column is hidden, by the keys of the hidden columns
+    fn hidden_mask<Column>(
+        columns: &[Column],
+        hidden_columns: &[u64],
+        column_key: Option<fn(&Column) -> u64>,
+    ) -> Vec<bool> {
+        match column_key {
+            Some(column_key) => columns
+                .iter()
+                .map(|column| hidden_columns.contains(&(column_key)(column)))
+                .collect(),
+            None => vec![false; columns.len()],
+        }
+    }
+
+    // The sorts applied to the rows, leaving out the unsorted & unsortable columns
+    fn applied_sorting<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        sorting: &[Sort],
+    ) -> Vec<Sort>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        sorting
+            .iter()
+            .filter(|sort| {
+                sort.direction != SortDirection::Unsorted
+                    && columns
+                        .get(sort.column)
+                        .map_or(false, |column| column.is_sortable())
+            })
+            .copied()
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn header_container<'a, 'b, Column, Row, Message, Renderer>(
+        index: usize,
+        column: &'b Column,
+        widths: Rc<Widths>,
+        sort: Option<(SortDirection, Option<usize>)>,
+        resizing: Option<Resizing<Message>>,
+        divider_width: f32,
+        cell_padding: Padding,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + iced_core::text::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet + text::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let width = column.width();
+        let is_resizable = matches!(width, Width::Resizable { .. });
+        let header = column.header(index);
+
+        let header = match sort {
+            Some((direction, priority)) => {
+                let arrow = match direction {
+                    SortDirection::Descending => "▼",
+                    _ => "▲",
+                };
+                let label = match priority {
+                    Some(priority) => format!("{arrow}{priority}"),
+                    None => arrow.to_string(),
+                };
+
+                row![
+                    header,
+                    style::wrapper::sort_indicator(text(label), style.clone())
+                ]
+                .spacing(4)
+                .align_items(Alignment::Center)
+                .into()
+            }
+            None => header,
+        };
+
+        let content = container(Measure::new(
+            header,
+            index,
+            extra_width(is_resizable, divider_width, cell_padding),
+            widths.clone(),
+        ))
+        .width(Length::Fill)
+        .padding(cell_padding)
+        .into();
+
+        with_divider(
+            index,
+            width,
+            widths,
+            content,
+            resizing,
+            divider_width,
+            style,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn body_container<'a, 'b, Column, Row, Message, Renderer>(
+        col_index: usize,
+        span: Vec<usize>,
+        row_path: &[usize],
+        widths: Rc<Widths>,
+        column: &'b Column,
+        row: &'b Row,
+        expander: Option<Element<'a, Message, Renderer>>,
+        draft: Option<Draft>,
+        divider_width: f32,
+        mut cell_padding: Padding,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let is_resizable = matches!(column.width(), Width::Resizable { .. });
+        let extra = extra_width(is_resizable, divider_width, cell_padding);
+
+        if is_resizable {
+            cell_padding.right += divider_width;
+        }
+
+        // The editor is given the value it last produced
+        let editor = draft.and_then(|draft| {
+            let editor = column.editor(col_index, row_path, row, &draft.borrow())?;
+
+            Some(Editor::new(editor, draft).into())
+        });
+
+        let cell = editor.unwrap_or_else(|| column.cell(col_index, row_path, row));
+
+        // Measured along with the cell, to fit the deepest rows
+        let cell = match expander {
+            Some(expander) => row![expander, cell].align_items(Alignment::Center).into(),
+            None => cell,
+        };
+
+        // Spanning cells are left to the widths of their columns
+        let content = if span.len() > 1 {
+            cell
+        } else {
+            Measure::new(cell, col_index, extra, widths.clone()).into()
+        };
+
+        Cell::spanning(container(content).padding(cell_padding), span, widths).into()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn footer_container<'a, 'b, Column, Row, Message, Renderer>(
+        index: usize,
+        column: &'b Column,
+        widths: Rc<Widths>,
+        rows: &[&'b Row],
+        resizing: Option<Resizing<Message>>,
+        divider_width: f32,
         cell_padding: Padding,
         style: <Renderer::Theme as style::StyleSheet>::Style,
     ) -> Element<'a, Message, Renderer>
@@ -372,28 +2222,41 @@ pub mod table {
         Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = container(column.header(index))
+        let width = column.width();
+        let is_resizable = matches!(width, Width::Resizable { .. });
+
+        let content = if let Some(footer) = column.footer(index, rows) {
+            container(Measure::new(
+                footer,
+                index,
+                extra_width(is_resizable, divider_width, cell_padding),
+                widths.clone(),
+            ))
             .width(Length::Fill)
             .padding(cell_padding)
-            .into();
+            .center_y()
+            .into()
+        } else {
+            Element::from(Space::with_width(Length::Fill))
+        };
 
         with_divider(
             index,
-            calculated_width,
+            width,
+            widths,
             content,
-            on_drag,
-            on_release,
+            resizing,
             divider_width,
             style,
         )
     }
 
-    fn body_container<'a, 'b, Column, Row, Message, Renderer>(
-        col_index: usize,
-        row_index: usize,
-        calculated_width: CalculatedWidth,
+    fn subtotal_container<'a, 'b, Column, Row, Message, Renderer>(
+        index: usize,
         column: &'b Column,
-        row: &'b Row,
+        widths: Rc<Widths>,
+        group: &str,
+        rows: &[&'b Row],
         divider_width: f32,
         mut cell_padding: Padding,
     ) -> Element<'a, Message, Renderer>
@@ -403,60 +2266,202 @@ pub mod table {
         Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        if calculated_width.is_resizable {
+        let is_resizable = matches!(column.width(), Width::Resizable { .. });
+        let extra = extra_width(is_resizable, divider_width, cell_padding);
+
+        if is_resizable {
             cell_padding.right += divider_width;
         }
 
-        container(column.cell(col_index, row_index, row))
-            .width(calculated_width.current)
-            .padding(cell_padding)
+        let content = match column.group_footer(index, group, rows) {
+            Some(footer) => container(Measure::new(footer, index, extra, widths.clone()))
+                .padding(cell_padding)
+                .into(),
+            None => Element::from(Space::with_width(Length::Fill)),
+        };
+
+        Cell::new(content, index, widths).into()
+    }
+
+    // Indents a row of a tree by its `depth`, followed by a toggle if it has children
+    fn expander<'a, Row, Message, Renderer>(
+        tree: Tree<'_, Row>,
+        row: &Row,
+        depth: usize,
+        on_toggle: Option<fn(u64, bool) -> Message>,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + iced_core::text::Renderer + 'a,
+        Renderer::Theme: text::StyleSheet,
+        Message: 'a + Clone,
+    {
+        let toggle = if (tree.children)(row).is_empty() {
+            Element::from(Space::with_width(TREE_INDENT))
+        } else {
+            let is_expanded = tree.is_expanded(row);
+            let chevron = if is_expanded { "▼" } else { "▶" };
+            let on_toggle = on_toggle.map(|on_toggle| (on_toggle)((tree.key)(row), !is_expanded));
+
+            Toggle::new(container(text(chevron)).width(TREE_INDENT), on_toggle).into()
+        };
+
+        row![Space::with_width(depth as f32 * TREE_INDENT), toggle]
+            .align_items(Alignment::Center)
             .into()
     }
 
-    fn footer_container<'a, 'b, Column, Row, Message, Renderer>(
-        index: usize,
-        column: &'b Column,
-        calculated_width: CalculatedWidth,
-        rows: &'b [Row],
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+    // The row heading `group` across the visible columns, toggling it when clicked
+    fn group_header<'a, Message, Renderer>(
+        group: &Group,
+        visible: &[usize],
+        widths: Rc<Widths>,
+        row_height: Option<f32>,
+        cell_padding: Padding,
+        on_toggle: Option<fn(String) -> Message>,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + iced_core::text::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet + text::StyleSheet,
+        Message: 'a + Clone,
+    {
+        let chevron = if group.is_collapsed { "▶" } else { "▼" };
+
+        let content = container(
+            row![
+                text(chevron),
+                text(&group.key),
+                text(format!("({})", group.rows.len()))
+            ]
+            .spacing(4)
+            .align_items(Alignment::Center),
+        )
+        .width(Length::Fill)
+        .padding(cell_padding)
+        .center_y();
+
+        let content = match row_height {
+            Some(row_height) => content.height(row_height),
+            None => content,
+        };
+
+        let on_toggle = on_toggle.map(|on_toggle| (on_toggle)(group.key.clone()));
+
+        style::wrapper::group_header(
+            Cell::spanning(Toggle::new(content, on_toggle), visible.to_vec(), widths),
+            style,
+        )
+    }
+
+    // A row of bands above the header, each spanning the consecutive visible columns of its
+    // group which are pinned alike
+    fn header_band<'a, Message, Renderer>(
+        groups: &[ColumnGroup],
+        visible: &[usize],
+        pinned: &[Option<Pinned>],
+        widths: &Rc<Widths>,
         divider_width: f32,
         cell_padding: Padding,
         style: <Renderer::Theme as style::StyleSheet>::Style,
     ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + iced_core::text::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet + text::StyleSheet,
+        Message: 'a + Clone,
+    {
+        let edge = |index: usize| pinned[index].as_ref().map(|pinned| pinned.edge);
+        let mut spans: Vec<(Option<&ColumnGroup>, Vec<usize>)> = vec![];
+
+        for &index in visible {
+            let group = groups.iter().find(|group| group.columns.contains(&index));
+
+            match spans.last_mut() {
+                Some((Some(last), columns))
+                    if group == Some(*last) && edge(columns[0]) == edge(index) =>
+                {
+                    columns.push(index);
+                }
+                _ => spans.push((group, vec![index])),
+            }
+        }
+
+        let cells = spans
+            .into_iter()
+            .map(|(group, columns)| {
+                let content = match group {
+                    Some(group) => container(text(&group.label))
+                        .width(Length::Fill)
+                        .padding(cell_padding)
+                        .center_x()
+                        .into(),
+                    None => Element::from(Space::with_width(Length::Fill)),
+                };
+
+                // Stuck by the column nearest to its edge, drawing the boundary of any of them
+                let is_boundary = columns.iter().any(|&index| {
+                    pinned[index]
+                        .as_ref()
+                        .map_or(false, |pinned| pinned.is_boundary)
+                });
+                let pinned = columns
+                    .iter()
+                    .filter_map(|&index| pinned[index].as_ref())
+                    .min_by_key(|pinned| pinned.before.len())
+                    .map(|nearest| Pinned {
+                        is_boundary,
+                        ..nearest.clone()
+                    });
+
+                pin(
+                    Cell::spanning(content, columns, widths.clone()).into(),
+                    pinned.as_ref(),
+                    widths,
+                    Target::Header,
+                    divider_width,
+                    style.clone(),
+                )
+            })
+            .collect();
+
+        style::wrapper::header(row(cells), style)
+    }
+
+    fn filter_container<'a, 'b, Column, Row, Message, Renderer>(
+        index: usize,
+        column: &'b Column,
+        widths: Rc<Widths>,
+        divider_width: f32,
+        mut cell_padding: Padding,
+    ) -> Element<'a, Message, Renderer>
     where
         Renderer: iced_core::Renderer + 'a,
         Renderer::Theme: style::StyleSheet + container::StyleSheet,
         Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let content = if let Some(footer) = column.footer(index, rows) {
-            container(footer)
+        // Clear of the divider of the header above
+        if matches!(column.width(), Width::Resizable { .. }) {
+            cell_padding.right += divider_width;
+        }
+
+        // Not measured, as filters usually fill their column
+        let content = match column.filter(index) {
+            Some(filter) => container(filter)
                 .width(Length::Fill)
                 .padding(cell_padding)
-                .center_y()
-                .into()
-        } else {
-            Element::from(Space::with_width(Length::Fill))
+                .into(),
+            None => Element::from(Space::with_width(Length::Fill)),
         };
 
-        with_divider(
-            index,
-            calculated_width,
-            content,
-            on_drag,
-            on_release,
-            divider_width,
-            style,
-        )
+        Cell::new(content, index, widths).into()
     }
 
     fn with_divider<'a, Message, Renderer>(
         index: usize,
-        calculated_width: CalculatedWidth,
+        width: Width,
+        widths: Rc<Widths>,
         content: Element<'a, Message, Renderer>,
-        on_drag: Option<fn(usize, f32) -> Message>,
-        on_release: Option<Message>,
+        resizing: Option<Resizing<Message>>,
         divider_width: f32,
         style: <Renderer::Theme as style::StyleSheet>::Style,
     ) -> Element<'a, Message, Renderer>
@@ -465,83 +2470,485 @@ pub mod table {
         Renderer::Theme: style::StyleSheet + container::StyleSheet,
         Message: 'a + Clone,
     {
-        let current = calculated_width.current;
-        if let Some((on_drag, on_release)) = on_drag.zip(on_release) {
-            if calculated_width.is_resizable {
-                return container(Divider::new(
+        let (resizing, initial, min, max) = match (resizing, width) {
+            (
+                Some(resizing),
+                Width::Resizable {
+                    initial, min, max, ..
+                },
+            ) => (resizing, initial, min, max),
+            _ => return Cell::new(content, index, widths).into(),
+        };
+
+        let divider = match resizing {
+            Resizing::Messages {
+                on_drag,
+                on_release,
+                on_autofit,
+            } => {
+                // The offset is relative to the initial width
+                let divider = Divider::new(
                     content,
                     divider_width,
-                    move |offset| (on_drag)(index, offset),
-                    on_release,
+                    move |offset| Some((on_drag)(index, offset)),
+                    move || Some(on_release.clone()),
                     style,
-                ))
-                .width(current)
-                .into();
+                )
+                .range(
+                    min.map_or(f32::NEG_INFINITY, |min| min - initial),
+                    max.map_or(f32::INFINITY, |max| max - initial),
+                );
+
+                match on_autofit {
+                    Some(on_autofit) => {
+                        let widths = widths.clone();
+
+                        divider.on_double_click(move || {
+                            Some((on_autofit)(
+                                index,
+                                clamp_width(widths.fit(index), min, max),
+                            ))
+                        })
+                    }
+                    None => divider,
+                }
+            }
+            Resizing::Managed { on_resized } => {
+                let resized = {
+                    let widths = widths.clone();
+
+                    move || on_resized.map(|on_resized| (on_resized)(widths.resolved()))
+                };
+
+                let on_drag = {
+                    let widths = widths.clone();
+
+                    move |offset| {
+                        widths.drag(index, offset);
+                        None
+                    }
+                };
+
+                let on_release = {
+                    let widths = widths.clone();
+                    let resized = resized.clone();
+
+                    move || {
+                        widths.release();
+                        resized()
+                    }
+                };
+
+                let on_double_click = {
+                    let widths = widths.clone();
+
+                    move || {
+                        widths.set(index, widths.fit(index));
+                        resized()
+                    }
+                };
+
+                Divider::new(content, divider_width, on_drag, on_release, style)
+                    .on_double_click(on_double_click)
+            }
+        };
+
+        Cell::new(divider, index, widths).into()
+    }
+
+    // Width around the content of a cell, for it to be measured as a whole
+    fn extra_width(is_resizable: bool, divider_width: f32, cell_padding: Padding) -> f32 {
+        if is_resizable {
+            cell_padding.horizontal() + divider_width
+        } else {
+            cell_padding.horizontal()
+        }
+    }
+
+    // Keeps a pinned column against its edge, drawing the freeze boundary on the last one.
+    fn pin<'a, Message, Renderer>(
+        content: Element<'a, Message, Renderer>,
+        pinned: Option<&Pinned>,
+        widths: &Rc<Widths>,
+        target: Target,
+        divider_width: f32,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet,
+        Message: 'a,
+    {
+        let Some(pinned) = pinned else {
+            return content;
+        };
+
+        let offset = {
+            let before = pinned.before.clone();
+            let widths = widths.clone();
+
+            move || before.iter().map(|&index| widths.width(index)).sum()
+        };
+
+        let sticky = Sticky::new(content, pinned.edge, offset, target, style);
+
+        if pinned.is_boundary {
+            sticky.boundary(divider_width).into()
+        } else {
+            sticky.into()
+        }
+    }
+
+    // The edge each pinned column sticks to, along with the columns pinned between it and
+    // that edge. Hidden columns are left out.
+    fn pinned_columns<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        hidden: &[bool],
+    ) -> Vec<Option<Pinned>>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let mut pinned = vec![None; columns.len()];
+
+        for (pin, edge) in [
+            (Pin::Left, sticky::Edge::Left),
+            (Pin::Right, sticky::Edge::Right),
+        ] {
+            let indices: Vec<usize> = match pin {
+                Pin::Left => (0..columns.len()).collect(),
+                Pin::Right => (0..columns.len()).rev().collect(),
+            };
+            let is_pinned = |index: usize| columns[index].pin() == Some(pin) && !hidden[index];
+            let boundary = indices
+                .iter()
+                .rev()
+                .find(|&&index| is_pinned(index))
+                .copied();
+            let mut before = vec![];
+
+            for index in indices {
+                if is_pinned(index) {
+                    pinned[index] = Some(Pinned {
+                        edge,
+                        before: before.clone(),
+                        is_boundary: boundary == Some(index),
+                    });
+
+                    before.push(index);
+                }
             }
         }
 
-        container(content).width(current).into()
+        pinned
     }
 
-    // If there is no fill column, return `remaining_width` if positive.
-    //
-    // If there is at least one, then distribute the remaining width, based on their proportions,
-    // to fill the remaining width.
-    //
-    // If there is no remaining space or if the distributed width is less than their minimum width,
-    // then use the minimum instead.
-    fn distribute_fill_widths<'a, 'b, Column, Row, Message, Renderer>(
+    // Indices of the rows matching the filter of every visible column, and the search if any.
+    // The first `pinned_rows` rows are always kept.
+    fn filter_rows<'a, 'b, Column, Row, Message, Renderer>(
         columns: &'b [Column],
-        min_width: f32,
-    ) -> (Vec<CalculatedWidth>, Option<f32>)
+        rows: &'b [Row],
+        visible: &[usize],
+        search: &str,
+        pinned_rows: usize,
+    ) -> Vec<usize>
     where
         Renderer: iced_core::Renderer + 'a,
         Renderer::Theme: style::StyleSheet + container::StyleSheet,
         Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
         Message: 'a + Clone,
     {
-        let mut fill_proportion = 0;
-        let mut remaining_width = min_width;
-
-        columns.iter().for_each(|column| match column.width() {
-            Width::Fixed(current) => remaining_width -= current,
-            Width::Resizable {
-                initial, offset, ..
-            } => remaining_width -= initial + offset,
-            Width::Fill { proportion, .. } => fill_proportion += proportion,
-        });
+        let is_searched = |row: &Row| {
+            search.is_empty()
+                || visible.iter().any(|&column| {
+                    columns[column]
+                        .text(row)
+                        .map_or(false, |text| search::is_match(&text, search))
+                })
+        };
 
-        // Calculate the width of a single part to avoid division for every fill column
-        let part_width = if fill_proportion != 0 {
-            remaining_width / fill_proportion as f32
-        } else {
-            0.0
+        (0..rows.len())
+            .filter(|&index| {
+                index < pinned_rows
+                    || (visible
+                        .iter()
+                        .all(|&column| columns[column].matches_filter(&rows[index]))
+                        && is_searched(&rows[index]))
+            })
+            .collect()
+    }
+
+    // The rows in `order` grouped by their key, in order of their first row
+    fn group_rows<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        rows: &'b [Row],
+        visible: &[usize],
+        order: &[usize],
+        group_by: fn(&Row) -> String,
+        collapsed_groups: &[String],
+    ) -> Vec<Group>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let mut groups: Vec<Group> = vec![];
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let has_subtotal = visible
+            .iter()
+            .any(|&index| columns[index].has_group_footer());
+
+        for &index in order {
+            let position = *positions
+                .entry((group_by)(&rows[index]))
+                .or_insert_with_key(|key| {
+                    groups.push(Group {
+                        key: key.clone(),
+                        rows: vec![],
+                        is_collapsed: collapsed_groups.contains(key),
+                        has_subtotal,
+                    });
+
+                    groups.len() - 1
+                });
+
+            groups[position].rows.push(index);
+        }
+
+        groups
+    }
+
+    // The rows of the body in order, with each group heading its rows unless collapsed.
+    // The first `pinned_rows` rows are left ungrouped.
+    fn body_entries(order: &[usize], pinned_rows: usize, groups: Option<&[Group]>) -> Vec<Entry> {
+        let Some(groups) = groups else {
+            return order.iter().map(|&index| Entry::Row(index)).collect();
         };
 
-        let calculated_widths = columns
+        let grouped = groups.iter().enumerate().flat_map(|(index, group)| {
+            let rows = if group.is_collapsed {
+                &[][..]
+            } else {
+                &group.rows[..]
+            };
+            let subtotal = (!group.is_collapsed && group.has_subtotal).then_some(index);
+
+            std::iter::once(Entry::Group(index))
+                .chain(rows.iter().map(|&index| Entry::Row(index)))
+                .chain(subtotal.map(Entry::Subtotal))
+        });
+
+        order[..pinned_rows]
             .iter()
-            .map(|column| match column.width() {
-                Width::Fixed(current) => CalculatedWidth {
-                    current,
-                    is_resizable: false,
-                },
-                Width::Resizable { initial, offset } => CalculatedWidth {
-                    current: initial + offset,
-                    is_resizable: true
-                },
-                Width::Fill {
-                    proportion,
-                    minimum,
-                } => CalculatedWidth {
-                    current: (proportion as f32 * part_width).max(minimum),
-                    is_resizable: false,
-                },
+            .map(|&index| Entry::Row(index))
+            .chain(grouped)
+            .collect()
+    }
+
+    // Inserts the descendants of each expanded row beneath it, keeping them in `children`.
+    // Returns the entries along with how many of them descend from the pinned rows.
+    fn expand_rows<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        rows: &'b [Row],
+        entries: Vec<Entry>,
+        pinned_rows: usize,
+        sorting: &[Sort],
+        tree: Tree<'_, Row>,
+        children: &mut Vec<Child<'b, Row>>,
+    ) -> (Vec<Entry>, usize)
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let mut expanded = vec![];
+        let mut pinned_entries = 0;
+
+        for (position, entry) in entries.into_iter().enumerate() {
+            expanded.push(entry);
+
+            if let Entry::Row(index) = entry {
+                // Depth first, so each row is followed by its own descendants
+                let mut stack = vec![(&rows[index], vec![index])];
+
+                while let Some((row, path)) = stack.pop() {
+                    if tree.is_expanded(row) {
+                        let mut row_children: Vec<(usize, &Row)> =
+                            (tree.children)(row).iter().enumerate().collect();
+
+                        row_children.sort_by(|(_, a), (_, b)| compare_rows(columns, sorting, a, b));
+                        stack.extend(
+                            row_children
+                                .into_iter()
+                                .rev()
+                                .map(|(child, row)| (row, [&path[..], &[child]].concat())),
+                        );
+                    }
+
+                    if path.len() > 1 {
+                        expanded.push(Entry::Child(children.len()));
+                        children.push(Child { row, path });
+                    }
+                }
+            }
+
+            if position < pinned_rows {
+                pinned_entries = expanded.len();
+            }
+        }
+
+        (expanded, pinned_entries)
+    }
+
+    // The cells spanning the rows beneath them, along with the cells they cover. Spans stop
+    // at the end of the pinned rows, or at the first group header or subtotal.
+    fn row_spans<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        rows: &'b [Row],
+        entries: &[Entry],
+        children: &[Child<'b, Row>],
+        visible: &[usize],
+        pinned: &[Option<Pinned>],
+        pinned_entries: usize,
+    ) -> RowSpans
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        let mut row_spans = RowSpans::default();
+
+        for (position, entry) in entries.iter().enumerate() {
+            let Some((_, row)) = entry_row(entry, rows, children) else {
+                continue;
+            };
+
+            let end = if position < pinned_entries {
+                pinned_entries
+            } else {
+                entries.len()
+            };
+            let following = entries[position..end]
+                .iter()
+                .take_while(|entry| matches!(entry, Entry::Row(_) | Entry::Child(_)))
+                .count();
+
+            let mut column = 0;
+
+            while column < visible.len() {
+                if row_spans.covered.contains(&(position, column)) {
+                    column += 1;
+                    continue;
+                }
+
+                let span = columns[visible[column]].span(row);
+                let spanned =
+                    spanned_columns(span, position, column, visible, pinned, &row_spans.covered);
+                let spanned_rows = span.rows.clamp(1, following);
+
+                if spanned_rows > 1 {
+                    row_spans.rows.insert((position, column), spanned_rows);
+                    row_spans
+                        .covered
+                        .extend(
+                            (position + 1..position + spanned_rows).flat_map(|position| {
+                                (column..column + spanned).map(move |column| (position, column))
+                            }),
+                        );
+                }
+
+                column += spanned;
+            }
+        }
+
+        row_spans
+    }
+
+    // The number of visible columns spanned by the cell at `column`, itself included. Pinned
+    // columns aren't spanned, nor the cells covered by the rows above.
+    fn spanned_columns(
+        span: Span,
+        position: usize,
+        column: usize,
+        visible: &[usize],
+        pinned: &[Option<Pinned>],
+        covered: &HashSet<(usize, usize)>,
+    ) -> usize {
+        if pinned[visible[column]].is_some() {
+            return 1;
+        }
+
+        1 + (column + 1..visible.len())
+            .take(span.columns.saturating_sub(1))
+            .take_while(|&next| {
+                pinned[visible[next]].is_none() && !covered.contains(&(position, next))
             })
-            .collect();
+            .count()
+    }
+
+    // The path & data of the row at an entry
+    fn entry_row<'e, 'b, Row>(
+        entry: &'e Entry,
+        rows: &'b [Row],
+        children: &'e [Child<'b, Row>],
+    ) -> Option<(&'e [usize], &'b Row)> {
+        match entry {
+            Entry::Row(index) => Some((slice::from_ref(index), &rows[*index])),
+            Entry::Child(index) => Some((&children[*index].path, children[*index].row)),
+            Entry::Group(_) | Entry::Subtotal(_) => None,
+        }
+    }
+
+    // Stable sort of the row indices by each sort in order of priority, so rows comparing
+    // equal keep their original order. The first `pinned_rows` rows are left in place.
+    fn sort_order<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        rows: &'b [Row],
+        mut order: Vec<usize>,
+        sorting: &[Sort],
+        pinned_rows: usize,
+    ) -> Vec<usize>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        if !sorting.is_empty() {
+            order[pinned_rows..]
+                .sort_by(|&a, &b| compare_rows(columns, sorting, &rows[a], &rows[b]));
+        }
+
+        order
+    }
 
-        let unused_width =
-            (remaining_width > 0.0 && fill_proportion == 0).then_some(remaining_width);
+    // Compares two rows by each sort in order of priority
+    fn compare_rows<'a, 'b, Column, Row, Message, Renderer>(
+        columns: &'b [Column],
+        sorting: &[Sort],
+        a: &Row,
+        b: &Row,
+    ) -> Ordering
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: style::StyleSheet + container::StyleSheet,
+        Column: self::Column<'a, 'b, Message, Renderer, Row = Row>,
+        Message: 'a + Clone,
+    {
+        sorting.iter().fold(Ordering::Equal, |ordering, sort| {
+            ordering.then_with(|| {
+                let ordering = columns[sort.column].compare(a, b);
 
-        (calculated_widths, unused_width)
+                match sort.direction {
+                    SortDirection::Descending => ordering.reverse(),
+                    _ => ordering,
+                }
+            })
+        })
     }
 }
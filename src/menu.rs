@@ -0,0 +1,133 @@
+use iced_core::layout::{self, Layout};
+use iced_core::widget::Tree;
+use iced_core::{event, keyboard, mouse, overlay, Element, Point, Rectangle, Size};
+use iced_core::{renderer, Clipboard, Shell};
+
+/// An [`Overlay`](overlay::Overlay) showing some content at the position it was opened at,
+/// kept within the window.
+///
+/// The menu closes itself by clearing its `position` once a mouse button is pressed outside of
+/// it, or Escape is pressed.
+pub(crate) struct Menu<'a, 'b, Message, Renderer> {
+    content: &'b mut Element<'a, Message, Renderer>,
+    tree: &'b mut Tree,
+    position: &'b mut Option<Point>,
+}
+
+impl<'a, 'b, Message, Renderer> Menu<'a, 'b, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    'a: 'b,
+{
+    pub fn new(
+        content: &'b mut Element<'a, Message, Renderer>,
+        tree: &'b mut Tree,
+        position: &'b mut Option<Point>,
+    ) -> Self {
+        Self {
+            content,
+            tree,
+            position,
+        }
+    }
+
+    /// Returns the overlay of the menu, if it's open.
+    pub fn overlay(self) -> Option<overlay::Element<'b, Message, Renderer>> {
+        let position = (*self.position)?;
+
+        Some(overlay::Element::new(position, Box::new(self)))
+    }
+}
+
+impl<'a, 'b, Message, Renderer> overlay::Overlay<Message, Renderer>
+    for Menu<'a, 'b, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+        let mut node = self.content.as_widget().layout(renderer, &limits);
+        let size = node.size();
+
+        // Opened towards the top left when there's no room left
+        node.move_to(Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            position.y.min(bounds.height - size.height).max(0.0),
+        ));
+
+        node
+    }
+
+    fn on_event(
+        &mut self,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            event::Event::Mouse(mouse::Event::ButtonPressed(_))
+                if !layout.bounds().contains(cursor_position) =>
+            {
+                *self.position = None;
+                return event::Status::Captured;
+            }
+            event::Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) => {
+                *self.position = None;
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            self.tree,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+}
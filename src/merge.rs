@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, mouse, overlay, Color, Element, Length, Point, Rectangle};
+use iced_core::{renderer, Clipboard, Shell};
+
+use crate::style::{self, wrapper::Target};
+
+/// The height of each row of a [`Body`](crate::body::Body), by position, as last laid out.
+pub(crate) type Heights = Rc<RefCell<Vec<f32>>>;
+
+/// A cell merged with the cells beneath it, down to the last of its `rows`, which are left
+/// empty.
+///
+/// The cell keeps its own height while laid out, so it doesn't grow its row. It is drawn
+/// over the rows it spans instead, in its own layer and over the background of its row's
+/// [`Target`], beneath the `pinned` rows unless it's one of them.
+pub(crate) struct Merged<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    rows: Range<usize>,
+    pinned: usize,
+    heights: Heights,
+    target: Target,
+    style: <Renderer::Theme as style::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Merged<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        rows: Range<usize>,
+        pinned: usize,
+        heights: Heights,
+        target: Target,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            rows,
+            pinned,
+            heights,
+            target,
+            style,
+        }
+    }
+
+    // The bounds of the cell, stretched down to the last row it spans
+    fn merged_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let height = self
+            .heights
+            .borrow()
+            .get(self.rows.clone())
+            .map_or(bounds.height, |heights| heights.iter().sum());
+
+        Rectangle {
+            height: height.max(bounds.height),
+            ..bounds
+        }
+    }
+
+    // The part of the viewport left by the pinned rows, unless this cell is pinned itself
+    fn clip_bounds(&self, viewport: Rectangle) -> Rectangle {
+        if self.rows.start < self.pinned {
+            return viewport;
+        }
+
+        let pinned: f32 = self.heights.borrow().iter().take(self.pinned).sum();
+
+        Rectangle {
+            y: viewport.y + pinned,
+            height: (viewport.height - pinned).max(0.0),
+            ..viewport
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Merged<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let appearance = self.target.appearance::<Renderer>(theme, &self.style);
+        let bounds = self.merged_bounds(layout.bounds());
+
+        let style = appearance
+            .text_color
+            .map(|text_color| renderer::Style { text_color })
+            .unwrap_or(*style);
+
+        // Above the rows drawn after this one
+        renderer.with_layer(self.clip_bounds(*viewport), |renderer| {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                appearance
+                    .background
+                    .unwrap_or_else(|| Color::TRANSPARENT.into()),
+            );
+
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                &style,
+                layout,
+                cursor_position,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<Merged<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn from(merged: Merged<'a, Message, Renderer>) -> Self {
+        Element::new(merged)
+    }
+}
@@ -0,0 +1,90 @@
+use std::ops::Range;
+
+/// Returns the byte ranges of `text` matching the `query` of a
+/// [`Table::search`](crate::Table::search), ignoring case.
+///
+/// This can be used by [`Column::cell`](crate::table::Column::cell) to highlight the matched
+/// text. Matches don't overlap, and an empty `query` matches nothing.
+pub fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    let mut ranges = vec![];
+
+    if query.is_empty() {
+        return ranges;
+    }
+
+    let mut start = 0;
+
+    while let Some(c) = text[start..].chars().next() {
+        match matched_len(&text[start..], query) {
+            Some(len) => {
+                ranges.push(start..start + len);
+                start += len;
+            }
+            None => start += c.len_utf8(),
+        }
+    }
+
+    ranges
+}
+
+/// Returns `true` if `text` contains the `query` of a [`Table::search`](crate::Table::search),
+/// ignoring case.
+pub(crate) fn is_match(text: &str, query: &str) -> bool {
+    !query.is_empty() && !match_ranges(text, query).is_empty()
+}
+
+// Length of the start of `text` matching `query`, ignoring case
+fn matched_len(text: &str, query: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+
+    for expected in query.chars() {
+        let (_, c) = chars.next()?;
+
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+
+    Some(chars.next().map_or(text.len(), |(index, _)| index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_ignoring_case() {
+        assert_eq!(match_ranges("Cell A1", "cell"), vec![0..4]);
+        assert_eq!(match_ranges("abc ABC aBc", "Abc"), vec![0..3, 4..7, 8..11]);
+        assert!(is_match("Cell A1", "a1"));
+        assert!(!is_match("Cell A1", "b1"));
+    }
+
+    #[test]
+    fn matches_dont_overlap() {
+        assert_eq!(match_ranges("aaaa", "aa"), vec![0..2, 2..4]);
+        assert_eq!(match_ranges("aaa", "aa"), vec![0..2]);
+    }
+
+    #[test]
+    fn empty_query_matches_nothing() {
+        assert!(match_ranges("text", "").is_empty());
+        assert!(!is_match("text", ""));
+        assert!(!is_match("", ""));
+    }
+
+    #[test]
+    fn ranges_are_byte_ranges_of_the_text() {
+        let text = "Ünïcödé ÜNÏ";
+        let ranges = match_ranges(text, "üNï");
+
+        assert_eq!(ranges, vec![0..5, 12..17]);
+        assert_eq!(&text[ranges[1].clone()], "ÜNÏ");
+    }
+
+    #[test]
+    fn query_longer_than_the_rest_of_the_text_doesnt_match() {
+        assert!(match_ranges("abc", "bcd").is_empty());
+        assert_eq!(match_ranges("abcab", "ab"), vec![0..2, 3..5]);
+    }
+}
@@ -0,0 +1,750 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, mouse, overlay, Element, Length, Point, Rectangle, Size};
+use iced_core::{renderer, Clipboard, Shell};
+
+use crate::table::Width;
+
+/// The widths of the columns of a [`Table`](crate::Table), shared by all of its cells.
+///
+/// The widths are resolved as the table is laid out, so [`Width::Auto`] columns can be
+/// measured first. Measuring is done by laying the table out once while
+/// [`is_measuring`](Self::is_measuring), during which every [`Measure`] records the
+/// intrinsic width of its content.
+///
+/// Every column is measured, so the measured width of any column can be used to fit it
+/// to its content. Resizable columns are also measured whenever they're laid out when
+/// `always_measure`, so they're fitted to the cells as currently built rather than as
+/// last measured.
+///
+/// When the columns have `keys`, [`Width::Resizable`] columns can also be resized by the
+/// table itself, overriding their width. Those widths are kept by key as [`Resized`].
+#[derive(Debug)]
+pub(crate) struct Widths {
+    widths: Vec<Width>,
+    keys: Vec<u64>,
+    hidden: Vec<bool>,
+    min_width: f32,
+    // Measure even without auto columns
+    always_measure: bool,
+    // Rows shown & revision of the content; measured widths are only reused while this
+    // stays the same
+    key: (u64, u64),
+    sizing: RefCell<Sizing>,
+}
+
+/// The widths measured by a [`Widths`], which can be restored by the next one for the same
+/// columns, rows shown & revision of the content.
+#[derive(Debug, Clone)]
+pub(crate) struct Measured {
+    key: (u64, u64),
+    widths: Vec<Width>,
+    // Hidden columns aren't measured
+    hidden: Vec<bool>,
+    measured: Vec<f32>,
+}
+
+/// The widths of the columns resized by a [`Widths`], by the key of their column.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Resized {
+    widths: HashMap<u64, f32>,
+    // Key & offset of the column being resized
+    drag: Option<(u64, f32)>,
+}
+
+#[derive(Debug, Default)]
+struct Sizing {
+    is_measuring: bool,
+    measured: Option<Vec<f32>>,
+    // Widest cell of each resizable column laid out since, when fitting
+    fitted: Vec<f32>,
+    resized: Resized,
+    resolved: Vec<f32>,
+    unused: f32,
+}
+
+impl Widths {
+    pub fn new(
+        widths: Vec<Width>,
+        keys: Vec<u64>,
+        hidden: Vec<bool>,
+        min_width: f32,
+        key: (u64, u64),
+        always_measure: bool,
+    ) -> Rc<Self> {
+        let widths = Self {
+            widths,
+            keys,
+            hidden,
+            min_width,
+            always_measure,
+            key,
+            sizing: RefCell::default(),
+        };
+
+        widths.resolve();
+
+        Rc::new(widths)
+    }
+
+    /// Returns `true` if the columns should be, but haven't been, measured yet.
+    pub fn needs_measuring(&self) -> bool {
+        let should_measure = self.always_measure
+            || self
+                .widths
+                .iter()
+                .any(|width| matches!(width, Width::Auto { .. }));
+
+        should_measure && self.sizing.borrow().measured.is_none()
+    }
+
+    pub fn is_measuring(&self) -> bool {
+        self.sizing.borrow().is_measuring
+    }
+
+    /// Returns `true` if the cells of `column` should be measured as they're laid out, to
+    /// fit it to them.
+    pub fn is_fitting(&self, column: usize) -> bool {
+        self.always_measure && matches!(self.widths.get(column), Some(Width::Resizable { .. }))
+    }
+
+    /// Starts measuring, until the widths are [resolved](Self::resolve).
+    pub fn measure(&self) {
+        let mut sizing = self.sizing.borrow_mut();
+
+        sizing.is_measuring = true;
+        sizing.measured = Some(vec![0.0; self.widths.len()]);
+    }
+
+    /// Records the intrinsic `width` of a cell in `column`, while measuring or fitting.
+    pub fn record(&self, column: usize, width: f32) {
+        let mut sizing = self.sizing.borrow_mut();
+
+        if !width.is_finite() {
+            return;
+        }
+
+        if sizing.is_measuring {
+            if let Some(measured) = sizing
+                .measured
+                .as_mut()
+                .and_then(|measured| measured.get_mut(column))
+            {
+                *measured = measured.max(width);
+            }
+        } else if self.is_fitting(column) {
+            if sizing.fitted.len() <= column {
+                sizing.fitted.resize(self.widths.len(), 0.0);
+            }
+
+            sizing.fitted[column] = sizing.fitted[column].max(width);
+        }
+    }
+
+    /// The widest cell of each column, as last measured.
+    pub fn measured(&self) -> Option<Measured> {
+        let sizing = self.sizing.borrow();
+
+        sizing
+            .measured
+            .clone()
+            .filter(|_| !sizing.is_measuring)
+            .map(|measured| Measured {
+                key: self.key,
+                widths: self.widths.clone(),
+                hidden: self.hidden.clone(),
+                measured,
+            })
+    }
+
+    /// The width of `column` fitting its widest cell as currently laid out, or as last
+    /// measured, or its resolved width if it hasn't been measured.
+    pub fn fit(&self, column: usize) -> f32 {
+        let sizing = self.sizing.borrow();
+
+        sizing
+            .fitted
+            .get(column)
+            .copied()
+            .filter(|&fitted| fitted > 0.0)
+            .or_else(|| {
+                sizing
+                    .measured
+                    .as_ref()
+                    .and_then(|measured| measured.get(column).copied())
+            })
+            .unwrap_or_else(|| self.width(column))
+    }
+
+    /// Reuses the widths measured by a previous [`Widths`], if they're still valid.
+    pub fn restore(&self, measured: &Measured) {
+        // Resizing doesn't change the content of a column
+        let is_same_column = |a: &Width, b: &Width| {
+            a == b || matches!((a, b), (Width::Resizable { .. }, Width::Resizable { .. }))
+        };
+
+        if measured.key == self.key
+            && measured.hidden == self.hidden
+            && measured.widths.len() == self.widths.len()
+            && measured
+                .widths
+                .iter()
+                .zip(&self.widths)
+                .all(|(a, b)| is_same_column(a, b))
+        {
+            self.sizing.borrow_mut().measured = Some(measured.measured.clone());
+            self.resolve();
+        }
+    }
+
+    /// The widths resized by the table, to be [restored](Self::restore_resized) by the next
+    /// [`Widths`].
+    pub fn resized(&self) -> Resized {
+        self.sizing.borrow().resized.clone()
+    }
+
+    /// Reuses the widths resized by a previous [`Widths`].
+    pub fn restore_resized(&self, resized: &Resized) {
+        self.sizing.borrow_mut().resized = resized.clone();
+        self.resolve();
+    }
+
+    /// Offsets the width of `column` while its divider is dragged, if it has a key.
+    ///
+    /// The offset is kept within the `min` & `max` of the column, so the divider stops there
+    /// rather than drifting from the cursor.
+    pub fn drag(&self, column: usize, offset: f32) {
+        let Some(&key) = self.keys.get(column) else {
+            return;
+        };
+
+        let offset = match self.widths.get(column) {
+            Some(Width::Resizable {
+                initial, min, max, ..
+            }) => {
+                let width = self
+                    .sizing
+                    .borrow()
+                    .resized
+                    .widths
+                    .get(&key)
+                    .copied()
+                    .unwrap_or(*initial);
+
+                clamp_width(width + offset, *min, *max) - width
+            }
+            _ => offset,
+        };
+
+        self.sizing.borrow_mut().resized.drag = Some((key, offset));
+        self.resolve();
+    }
+
+    /// Applies the offset of the column being dragged to its width.
+    pub fn release(&self) {
+        let Some((key, _)) = self.sizing.borrow_mut().resized.drag.take() else {
+            return;
+        };
+
+        // The offset is still part of the resolved width
+        match self.keys.iter().position(|&other| other == key) {
+            Some(column) => self.set(column, self.width(column)),
+            None => self.resolve(),
+        }
+    }
+
+    /// Sets the width of `column`, if it has a key.
+    pub fn set(&self, column: usize, width: f32) {
+        let Some(&key) = self.keys.get(column) else {
+            return;
+        };
+
+        if let Some(Width::Resizable { min, max, .. }) = self.widths.get(column) {
+            self.sizing
+                .borrow_mut()
+                .resized
+                .widths
+                .insert(key, clamp_width(width, *min, *max));
+            self.resolve();
+        }
+    }
+
+    /// Stops measuring, and resolves the width of each column.
+    pub fn resolve(&self) {
+        let mut sizing = self.sizing.borrow_mut();
+
+        // Resized columns take their width from the table instead
+        let widths: Vec<Width> = self
+            .widths
+            .iter()
+            .enumerate()
+            .map(|(index, width)| match (*width, self.keys.get(index)) {
+                (
+                    Width::Resizable {
+                        initial,
+                        offset,
+                        min,
+                        max,
+                    },
+                    Some(key),
+                ) => Width::Resizable {
+                    initial: sizing.resized.widths.get(key).copied().unwrap_or(initial),
+                    offset: sizing
+                        .resized
+                        .drag
+                        .filter(|(dragged, _)| dragged == key)
+                        .map_or(offset, |(_, offset)| offset),
+                    min,
+                    max,
+                },
+                (width, _) => width,
+            })
+            .collect();
+
+        let (resolved, unused) = distribute_fill_widths(
+            &widths,
+            sizing.measured.as_deref().unwrap_or_default(),
+            &self.hidden,
+            self.min_width,
+        );
+
+        sizing.is_measuring = false;
+        sizing.resolved = resolved;
+        sizing.unused = unused.unwrap_or_default();
+    }
+
+    /// The resolved width of `column`.
+    pub fn width(&self, column: usize) -> f32 {
+        self.sizing
+            .borrow()
+            .resolved
+            .get(column)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The resolved width of every column.
+    pub fn resolved(&self) -> Vec<f32> {
+        self.sizing.borrow().resolved.clone()
+    }
+
+    /// The width left over by the columns when none of them fill the table.
+    pub fn unused(&self) -> f32 {
+        self.sizing.borrow().unused
+    }
+}
+
+/// Clamps `width` to the `min` & `max` of a [`Width::Resizable`], when they're set.
+pub(crate) fn clamp_width(width: f32, min: Option<f32>, max: Option<f32>) -> f32 {
+    let width = max.map_or(width, |max| width.min(max));
+
+    min.map_or(width, |min| width.max(min))
+}
+
+// If there is no fill column, return `remaining_width` if positive.
+//
+// If there is at least one, then distribute the remaining width, based on their proportions,
+// to fill the remaining width.
+//
+// If there is no remaining space or if the distributed width is less than their minimum width,
+// then use the minimum instead.
+//
+// Auto columns take their measured width, clamped to their bounds.
+//
+// Hidden columns are skipped, taking no width at all.
+fn distribute_fill_widths(
+    widths: &[Width],
+    measured: &[f32],
+    hidden: &[bool],
+    min_width: f32,
+) -> (Vec<f32>, Option<f32>) {
+    let is_hidden = |index: usize| hidden.get(index).copied().unwrap_or_default();
+
+    let auto = |index: usize, min: f32, max: f32| {
+        measured
+            .get(index)
+            .copied()
+            .unwrap_or_default()
+            .min(max)
+            .max(min)
+    };
+
+    let mut fill_proportion = 0;
+    let mut remaining_width = min_width;
+
+    widths
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !is_hidden(*index))
+        .for_each(|(index, width)| match *width {
+            Width::Fixed(current) => remaining_width -= current,
+            Width::Resizable {
+                initial,
+                offset,
+                min,
+                max,
+            } => remaining_width -= clamp_width(initial + offset, min, max),
+            Width::Fill { proportion, .. } => fill_proportion += proportion,
+            Width::Auto { min, max } => remaining_width -= auto(index, min, max),
+        });
+
+    // Calculate the width of a single part to avoid division for every fill column
+    let part_width = if fill_proportion != 0 {
+        remaining_width / fill_proportion as f32
+    } else {
+        0.0
+    };
+
+    let resolved = widths
+        .iter()
+        .enumerate()
+        .map(|(index, width)| match *width {
+            _ if is_hidden(index) => 0.0,
+            Width::Fixed(current) => current,
+            Width::Resizable {
+                initial,
+                offset,
+                min,
+                max,
+            } => clamp_width(initial + offset, min, max),
+            Width::Fill {
+                proportion,
+                minimum,
+            } => (proportion as f32 * part_width).max(minimum),
+            Width::Auto { min, max } => auto(index, min, max),
+        })
+        .collect();
+
+    let unused_width = (remaining_width > 0.0 && fill_proportion == 0).then_some(remaining_width);
+
+    (resolved, unused_width)
+}
+
+/// A cell taking the resolved width of its column, or the sum of the widths of the columns
+/// it spans.
+pub(crate) struct Cell<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    columns: Vec<usize>,
+    widths: Rc<Widths>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        column: usize,
+        widths: Rc<Widths>,
+    ) -> Self {
+        Self::spanning(content, vec![column], widths)
+    }
+
+    pub fn spanning(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        columns: Vec<usize>,
+        widths: Rc<Widths>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            columns,
+            widths,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Cell<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        Length::Fixed(
+            self.columns
+                .iter()
+                .map(|&column| self.widths.width(column))
+                .sum(),
+        )
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let limits = limits.width(self.width());
+        let content = self.content.as_widget().layout(renderer, &limits);
+        let size = limits.resolve(content.size());
+
+        layout::Node::with_children(Size::new(size.width, content.size().height), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Cell<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(cell: Cell<'a, Message, Renderer>) -> Self {
+        Element::new(cell)
+    }
+}
+
+/// Records the intrinsic width of its content, plus `extra` for the space around it,
+/// while the [`Widths`] are measuring, or fitting its column. Otherwise, it's laid out as
+/// its content.
+pub(crate) struct Measure<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    column: usize,
+    extra: f32,
+    widths: Rc<Widths>,
+}
+
+impl<'a, Message, Renderer> Measure<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        column: usize,
+        extra: f32,
+        widths: Rc<Widths>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            column,
+            extra,
+            widths,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Measure<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        self.content.as_widget().diff(tree)
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        if self.widths.is_measuring() || self.widths.is_fitting(self.column) {
+            let unbounded =
+                layout::Limits::new(Size::ZERO, Size::new(f32::INFINITY, limits.max().height));
+            let intrinsic = self.content.as_widget().layout(renderer, &unbounded);
+
+            self.widths
+                .record(self.column, intrinsic.size().width + self.extra);
+        }
+
+        self.content.as_widget().layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.as_widget_mut().on_event(
+            tree,
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            tree,
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(tree, layout, renderer)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, operation);
+    }
+}
+
+impl<'a, Message, Renderer> From<Measure<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(measure: Measure<'a, Message, Renderer>) -> Self {
+        Element::new(measure)
+    }
+}
@@ -0,0 +1,354 @@
+use std::cell::Cell;
+
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, mouse, overlay, Color, Element, Length, Point, Rectangle, Vector};
+use iced_core::{renderer, Clipboard, Shell};
+
+use crate::style::{self, wrapper::Target, StyleSheet};
+
+/// The edge of the viewport a [`Sticky`] sticks to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Edge {
+    Left,
+    Right,
+    Top,
+}
+
+#[derive(Debug)]
+struct State {
+    shift: Cell<Vector>,
+}
+
+/// Returns how far a child was last drawn from its layout, if it's a [`Sticky`].
+pub(crate) fn shift(tree: &Tree) -> Vector {
+    if tree.tag != widget::tree::Tag::of::<State>() {
+        return Vector::new(0.0, 0.0);
+    }
+
+    tree.state.downcast_ref::<State>().shift.get()
+}
+
+/// Returns the bounds of a child as drawn, shifted if it's a [`Sticky`].
+pub(crate) fn visible_bounds(tree: &Tree, layout: Layout<'_>) -> Rectangle {
+    layout.bounds() + shift(tree)
+}
+
+/// Returns the index of the child under `point`, favouring [`Sticky`] children as
+/// they're drawn above the others.
+pub(crate) fn child_at<'a>(
+    trees: &[Tree],
+    layouts: impl Iterator<Item = Layout<'a>>,
+    point: Point,
+) -> Option<usize> {
+    let mut hovered = None;
+
+    for (index, (tree, layout)) in trees.iter().zip(layouts).enumerate() {
+        if visible_bounds(tree, layout).contains(point) {
+            if tree.tag == widget::tree::Tag::of::<State>() {
+                return Some(index);
+            }
+
+            hovered = hovered.or(Some(index));
+        }
+    }
+
+    hovered
+}
+
+/// Keeps its content against an [`Edge`] of the viewport once it's scrolled past it,
+/// drawing it above the content it then overlaps.
+///
+/// The content is painted over a background matching its [`Target`], and a boundary may be
+/// drawn on the edge facing the scrolling content.
+pub(crate) struct Sticky<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    content: Element<'a, Message, Renderer>,
+    edge: Edge,
+    offset: Box<dyn Fn() -> f32 + 'a>,
+    boundary: Option<f32>,
+    target: Target,
+    style: <Renderer::Theme as style::StyleSheet>::Style,
+}
+
+impl<'a, Message, Renderer> Sticky<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    /// `offset` returns the distance to keep from the `edge`, e.g. the width of the sticky
+    /// columns before this one once they're laid out.
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        edge: Edge,
+        offset: impl Fn() -> f32 + 'a,
+        target: Target,
+        style: <Renderer::Theme as style::StyleSheet>::Style,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            edge,
+            offset: Box::new(offset),
+            boundary: None,
+            target,
+            style,
+        }
+    }
+
+    /// Draws a boundary of the given `width` on the edge facing the scrolling content.
+    pub fn boundary(self, width: f32) -> Self {
+        Self {
+            boundary: Some(width),
+            ..self
+        }
+    }
+
+    fn shift(&self, bounds: Rectangle, viewport: &Rectangle) -> Vector {
+        let offset = (self.offset)();
+
+        match self.edge {
+            Edge::Left => Vector::new((viewport.x + offset - bounds.x).max(0.0), 0.0),
+            Edge::Right => Vector::new(
+                (viewport.x + viewport.width - offset - (bounds.x + bounds.width)).min(0.0),
+                0.0,
+            ),
+            Edge::Top => Vector::new(0.0, (viewport.y + offset - bounds.y).max(0.0)),
+        }
+    }
+
+    fn draw_boundary(&self, renderer: &mut Renderer, theme: &Renderer::Theme, bounds: Rectangle) {
+        let Some(width) = self.boundary else {
+            return;
+        };
+
+        let appearance = theme.frozen_boundary(&self.style);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: self.boundary_bounds(bounds, width),
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance
+                .background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+    }
+
+    fn boundary_bounds(&self, bounds: Rectangle, width: f32) -> Rectangle {
+        match self.edge {
+            Edge::Left => Rectangle {
+                x: (bounds.x + bounds.width - width).floor(),
+                width,
+                ..bounds
+            },
+            Edge::Right => Rectangle {
+                x: bounds.x.floor(),
+                width,
+                ..bounds
+            },
+            Edge::Top => Rectangle {
+                y: (bounds.y + bounds.height - width).floor(),
+                height: width,
+                ..bounds
+            },
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Sticky<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State {
+            shift: Cell::new(Vector::new(0.0, 0.0)),
+        })
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let shift = tree.state.downcast_ref::<State>().shift.get();
+
+        self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout.children().next().unwrap(),
+            cursor_position - shift,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let shift = tree.state.downcast_ref::<State>().shift.get();
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position - shift,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let shift = self.shift(bounds, viewport);
+
+        state.shift.set(shift);
+
+        let draw = |renderer: &mut Renderer| {
+            // Hide the content we're drawn over
+            if shift != Vector::new(0.0, 0.0) {
+                let appearance = self.target.appearance::<Renderer>(theme, &self.style);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: appearance.border_radius,
+                        border_width: appearance.border_width,
+                        border_color: appearance.border_color,
+                    },
+                    appearance
+                        .background
+                        .unwrap_or_else(|| Color::TRANSPARENT.into()),
+                );
+            }
+
+            // Below the content of a column, so a divider on the same edge stays visible
+            if self.edge != Edge::Top {
+                self.draw_boundary(renderer, theme, bounds);
+            }
+
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                layout.children().next().unwrap(),
+                cursor_position - shift,
+                &Rectangle {
+                    x: viewport.x - shift.x,
+                    y: viewport.y - shift.y,
+                    ..*viewport
+                },
+            );
+
+            // Above the content of rows, as they fill their background
+            if self.edge == Edge::Top {
+                self.draw_boundary(renderer, theme, bounds);
+            }
+        };
+
+        if shift == Vector::new(0.0, 0.0) {
+            draw(renderer);
+        } else {
+            // A new layer is drawn above the content of the row it belongs to
+            renderer.with_layer(
+                Rectangle {
+                    x: bounds.x + shift.x,
+                    y: bounds.y + shift.y,
+                    ..bounds
+                },
+                |renderer| renderer.with_translation(shift, draw),
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Sticky<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: renderer::Renderer + 'a,
+    Renderer::Theme: style::StyleSheet,
+{
+    fn from(sticky: Sticky<'a, Message, Renderer>) -> Self {
+        Element::new(sticky)
+    }
+}
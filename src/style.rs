@@ -1,6 +1,10 @@
+use iced_core::Color;
 use iced_widget::container;
 
 /// A set of rules that dictate the styling of a [`Table`](crate::Table).
+///
+/// Only the header, footer, row & divider appearances are required. The others default to
+/// one of them, so a style sheet written against those keeps working.
 pub trait StyleSheet {
     /// The supported style of the [`StyleSheet`].
     type Style: Default + Clone;
@@ -11,8 +15,67 @@ pub trait StyleSheet {
     fn footer(&self, style: &Self::Style) -> container::Appearance;
     /// The row [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`].
     fn row(&self, style: &Self::Style, index: usize) -> container::Appearance;
+    /// The pinned row [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`].
+    fn pinned_row(&self, style: &Self::Style, index: usize) -> container::Appearance {
+        self.row(style, index)
+    }
+    /// The selected row [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`].
+    fn selected_row(&self, style: &Self::Style, _index: usize) -> container::Appearance {
+        self.header(style)
+    }
     /// The divider [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`].
     fn divider(&self, style: &Self::Style, hovered: bool) -> container::Appearance;
+    /// The focused cell [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`],
+    /// drawn over the cell as a focus ring.
+    fn focused_cell(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            border_width: 2.0,
+            border_color: self.header(style).text_color.unwrap_or(Color::BLACK),
+            ..Default::default()
+        }
+    }
+    /// The sort indicator [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`],
+    /// shown in the header of a sorted column.
+    fn sort_indicator(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            text_color: self.header(style).text_color,
+            ..Default::default()
+        }
+    }
+    /// The frozen boundary [`Appearance`](iced_widget::container::Appearance) of the
+    /// [`StyleSheet`], drawn between pinned & scrolling content.
+    fn frozen_boundary(&self, style: &Self::Style) -> container::Appearance {
+        self.divider(style, false)
+    }
+    /// The dragged header [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`],
+    /// drawn over the preview of a header being dragged to reorder its column.
+    fn dragged_header(&self, style: &Self::Style) -> container::Appearance {
+        // Drawn over the content of the preview, so it's left see-through
+        container::Appearance {
+            border_width: 1.0,
+            border_color: self.header(style).text_color.unwrap_or(Color::BLACK),
+            ..Default::default()
+        }
+    }
+    /// The column menu [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`],
+    /// listing the columns to show or hide when the header is right-clicked.
+    fn column_menu(&self, style: &Self::Style) -> container::Appearance {
+        self.header(style)
+    }
+    /// The group header [`Appearance`](iced_widget::container::Appearance) of the
+    /// [`StyleSheet`], spanning the row heading each group of rows.
+    fn group_header(&self, style: &Self::Style) -> container::Appearance {
+        self.header(style)
+    }
+    /// The selection [`Appearance`](iced_widget::container::Appearance) of the [`StyleSheet`],
+    /// drawn over a range of cells selected by dragging across them.
+    fn selection(&self, style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            border_width: 1.0,
+            border_color: self.header(style).text_color.unwrap_or(Color::BLACK),
+            ..Default::default()
+        }
+    }
 }
 
 impl StyleSheet for iced_style::Theme {
@@ -44,6 +107,26 @@ impl StyleSheet for iced_style::Theme {
         }
     }
 
+    fn pinned_row(&self, _style: &Self::Style, _index: usize) -> container::Appearance {
+        let pair = self.extended_palette().secondary.weak;
+
+        container::Appearance {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            ..Default::default()
+        }
+    }
+
+    fn selected_row(&self, _style: &Self::Style, _index: usize) -> container::Appearance {
+        let pair = self.extended_palette().primary.weak;
+
+        container::Appearance {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            ..Default::default()
+        }
+    }
+
     fn divider(&self, _style: &Self::Style, hovered: bool) -> container::Appearance {
         let pair = if hovered {
             self.extended_palette().primary.base
@@ -56,6 +139,84 @@ impl StyleSheet for iced_style::Theme {
             ..Default::default()
         }
     }
+
+    fn focused_cell(&self, _style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            border_width: 2.0,
+            border_color: self.extended_palette().primary.strong.color,
+            ..Default::default()
+        }
+    }
+
+    fn sort_indicator(&self, _style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            text_color: Some(self.extended_palette().primary.base.color),
+            ..Default::default()
+        }
+    }
+
+    fn frozen_boundary(&self, _style: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            background: Some(self.extended_palette().background.strong.color.into()),
+            ..Default::default()
+        }
+    }
+
+    fn dragged_header(&self, _style: &Self::Style) -> container::Appearance {
+        let pair = self.extended_palette().primary.weak;
+
+        container::Appearance {
+            background: Some(
+                Color {
+                    a: 0.5,
+                    ..pair.color
+                }
+                .into(),
+            ),
+            border_width: 1.0,
+            border_color: self.extended_palette().primary.strong.color,
+            ..Default::default()
+        }
+    }
+
+    fn column_menu(&self, _style: &Self::Style) -> container::Appearance {
+        let pair = self.extended_palette().background.base;
+
+        container::Appearance {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            border_width: 1.0,
+            border_color: self.extended_palette().background.strong.color,
+            ..Default::default()
+        }
+    }
+
+    fn group_header(&self, _style: &Self::Style) -> container::Appearance {
+        let pair = self.extended_palette().secondary.base;
+
+        container::Appearance {
+            text_color: Some(pair.text),
+            background: Some(pair.color.into()),
+            ..Default::default()
+        }
+    }
+
+    fn selection(&self, _style: &Self::Style) -> container::Appearance {
+        let pair = self.extended_palette().primary.weak;
+
+        container::Appearance {
+            background: Some(
+                Color {
+                    a: 0.25,
+                    ..pair.color
+                }
+                .into(),
+            ),
+            border_width: 1.0,
+            border_color: self.extended_palette().primary.base.color,
+            ..Default::default()
+        }
+    }
 }
 
 pub(crate) mod wrapper {
@@ -116,14 +277,107 @@ pub(crate) mod wrapper {
         .into()
     }
 
-    enum Target {
+    pub fn pinned_row<'a, Message, Renderer>(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as super::StyleSheet>::Style,
+        index: usize,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: super::StyleSheet,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::PinnedRow { index },
+            style,
+        }
+        .into()
+    }
+
+    pub fn selected_row<'a, Message, Renderer>(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as super::StyleSheet>::Style,
+        index: usize,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: super::StyleSheet,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::SelectedRow { index },
+            style,
+        }
+        .into()
+    }
+
+    pub fn sort_indicator<'a, Message, Renderer>(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as super::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: super::StyleSheet,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::SortIndicator,
+            style,
+        }
+        .into()
+    }
+
+    pub fn column_menu<'a, Message, Renderer>(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as super::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: super::StyleSheet,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::ColumnMenu,
+            style,
+        }
+        .into()
+    }
+
+    pub fn group_header<'a, Message, Renderer>(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        style: <Renderer::Theme as super::StyleSheet>::Style,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Renderer: iced_core::Renderer + 'a,
+        Renderer::Theme: super::StyleSheet,
+        Message: 'a,
+    {
+        Wrapper {
+            content: content.into(),
+            target: Target::GroupHeader,
+            style,
+        }
+        .into()
+    }
+
+    #[derive(Clone, Copy)]
+    pub enum Target {
         Header,
         Footer,
         Row { index: usize },
+        PinnedRow { index: usize },
+        SelectedRow { index: usize },
+        SortIndicator,
+        ColumnMenu,
+        GroupHeader,
     }
 
     impl Target {
-        fn appearance<Renderer>(
+        pub fn appearance<Renderer>(
             &self,
             theme: &Renderer::Theme,
             style: &<Renderer::Theme as super::StyleSheet>::Style,
@@ -136,6 +390,11 @@ pub(crate) mod wrapper {
                 Target::Header => theme.header(style),
                 Target::Footer => theme.footer(style),
                 Target::Row { index } => theme.row(style, *index),
+                Target::PinnedRow { index } => theme.pinned_row(style, *index),
+                Target::SelectedRow { index } => theme.selected_row(style, *index),
+                Target::SortIndicator => theme.sort_indicator(style),
+                Target::ColumnMenu => theme.column_menu(style),
+                Target::GroupHeader => theme.group_header(style),
             }
         }
     }
@@ -0,0 +1,183 @@
+//! Export the rows of a [`Table`](super::Table) as CSV or TSV, and read them back
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+/// The format of an [export](super::Table::export).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    /// Comma-separated values, as defined by RFC 4180, with records ending in CRLF.
+    Csv,
+    /// Tab-separated values, quoted like CSV, with records ending in LF.
+    Tsv,
+}
+
+impl Format {
+    fn delimiter(self) -> char {
+        match self {
+            Format::Csv => ',',
+            Format::Tsv => '\t',
+        }
+    }
+
+    fn line_ending(self) -> &'static str {
+        match self {
+            Format::Csv => "\r\n",
+            Format::Tsv => "\n",
+        }
+    }
+}
+
+/// Writes a record of `fields` to `writer` in the given [`Format`].
+///
+/// Fields containing the delimiter, a double quote or a line break are enclosed in double
+/// quotes, with their own double quotes doubled.
+pub fn write_record<S: AsRef<str>>(
+    writer: &mut impl Write,
+    format: Format,
+    fields: impl IntoIterator<Item = S>,
+) -> io::Result<()> {
+    for (index, field) in fields.into_iter().enumerate() {
+        if index > 0 {
+            write!(writer, "{}", format.delimiter())?;
+        }
+
+        writer.write_all(quote(field.as_ref(), format).as_bytes())?;
+    }
+
+    writer.write_all(format.line_ending().as_bytes())
+}
+
+/// Reads the records of `text` in the given [`Format`], undoing the quoting of
+/// [`write_record`]. The line break ending the last record is optional.
+pub fn read_records(text: &str, format: Format) -> Vec<Vec<String>> {
+    let mut records = vec![];
+    let mut record = vec![];
+    let mut field = String::new();
+    let mut is_quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if is_quoted {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => is_quoted = false,
+                c => field.push(c),
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => is_quoted = true,
+            c if c == format.delimiter() => record.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            c => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    records
+}
+
+fn quote(field: &str, format: Format) -> Cow<'_, str> {
+    let needs_quotes = field
+        .chars()
+        .any(|c| c == format.delimiter() || matches!(c, '"' | '\r' | '\n'));
+
+    if needs_quotes {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn written(format: Format, records: &[&[&str]]) -> String {
+        let mut contents = vec![];
+
+        for record in records {
+            write_record(&mut contents, format, *record).unwrap();
+        }
+
+        String::from_utf8(contents).unwrap()
+    }
+
+    #[test]
+    fn writes_plain_fields_as_is() {
+        assert_eq!(
+            written(Format::Csv, &[&["a", "b"], &["c", ""]]),
+            "a,b\r\nc,\r\n"
+        );
+        assert_eq!(
+            written(Format::Tsv, &[&["a", "b"], &["c", ""]]),
+            "a\tb\nc\t\n"
+        );
+    }
+
+    #[test]
+    fn quotes_fields_with_delimiters_quotes_or_line_breaks() {
+        assert_eq!(
+            written(Format::Csv, &[&["a,b", "say \"hi\"", "two\nlines", "cr\r"]]),
+            "\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",\"cr\r\"\r\n"
+        );
+        // Commas only need quoting in CSV, tabs in TSV
+        assert_eq!(written(Format::Tsv, &[&["a,b", "a\tb"]]), "a,b\t\"a\tb\"\n");
+    }
+
+    #[test]
+    fn reads_records_with_or_without_a_final_line_break() {
+        let expected = vec![vec!["a", "b"], vec!["c", "d"]];
+
+        assert_eq!(read_records("a\tb\nc\td\n", Format::Tsv), expected);
+        assert_eq!(read_records("a\tb\nc\td", Format::Tsv), expected);
+        assert_eq!(read_records("a,b\r\nc,d\r\n", Format::Csv), expected);
+    }
+
+    #[test]
+    fn reads_empty_fields() {
+        assert_eq!(
+            read_records("a\t\n\tb", Format::Tsv),
+            vec![vec!["a", ""], vec!["", "b"]]
+        );
+        assert_eq!(read_records("", Format::Tsv), Vec::<Vec<String>>::new());
+    }
+
+    #[test]
+    fn reads_quoted_fields() {
+        assert_eq!(
+            read_records(
+                "\"a\tb\"\t\"say \"\"hi\"\"\"\n\"two\r\nlines\"\n",
+                Format::Tsv
+            ),
+            vec![vec!["a\tb", "say \"hi\""], vec!["two\r\nlines"]]
+        );
+    }
+
+    #[test]
+    fn reads_back_what_was_written() {
+        let records: &[&[&str]] = &[
+            &["plain", "", "a,b", "a\tb"],
+            &["say \"hi\"", "two\nlines", "crlf\r\n", "\"quoted\""],
+            &["", "", "", "trailing"],
+            &["x", "", "", ""],
+        ];
+
+        for format in [Format::Csv, Format::Tsv] {
+            assert_eq!(read_records(&written(format, records), format), records);
+        }
+    }
+}
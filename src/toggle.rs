@@ -0,0 +1,173 @@
+use iced_core::layout::{self, Layout};
+use iced_core::widget::{self, Tree, Widget};
+use iced_core::{event, mouse, overlay, Element, Length, Point, Rectangle};
+use iced_core::{renderer, Clipboard, Shell};
+
+/// Some content emitting `on_toggle` when clicked, e.g. to expand or collapse a group of
+/// rows.
+pub(crate) struct Toggle<'a, Message, Renderer> {
+    content: Element<'a, Message, Renderer>,
+    on_toggle: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Toggle<'a, Message, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        on_toggle: Option<Message>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_toggle,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for Toggle<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.content]);
+    }
+
+    fn width(&self) -> Length {
+        self.content.as_widget().width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.as_widget().height()
+    }
+
+    fn layout(&self, renderer: &Renderer, limits: &layout::Limits) -> layout::Node {
+        let content = self.content.as_widget().layout(renderer, limits);
+
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: event::Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event.clone(),
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if status == event::Status::Ignored {
+            if let event::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) = event {
+                if let Some(on_toggle) = &self.on_toggle {
+                    if layout.bounds().contains(cursor_position) {
+                        shell.publish(on_toggle.clone());
+                        return event::Status::Captured;
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if interaction == mouse::Interaction::Idle
+            && self.on_toggle.is_some()
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Pointer
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Renderer::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'b, Message, Renderer>> {
+        self.content.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content.as_widget().operate(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            operation,
+        );
+    }
+}
+
+impl<'a, Message, Renderer> From<Toggle<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(toggle: Toggle<'a, Message, Renderer>) -> Self {
+        Element::new(toggle)
+    }
+}